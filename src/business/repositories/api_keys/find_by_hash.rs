@@ -0,0 +1,39 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::api_key::ApiKey, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::doc;
+use tracing::{event, Level};
+
+// Revoked keys are left out
+pub async fn find_by_hash(key_hash: &str) -> Result<Option<ApiKey>, VoyagerError> {
+  event!(Level::DEBUG, "Finding API key by hash in database");
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::api_keys::find_by_hash",
+      DB_CONTEXT
+        .api_keys
+        .find_one(doc! { "key_hash": key_hash, "revoked_at": null }, None),
+    )
+    .await?;
+
+  let result = result.map_err(|e| VoyagerError::find_api_key(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done finding API key");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn find_api_key(e: Error) -> Self {
+    Self::new(
+      "Failure while finding API key".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,14 @@
+mod find_by_hash;
+pub use find_by_hash::*;
+
+mod retrieve_all;
+pub use retrieve_all::*;
+
+mod revoke;
+pub use revoke::*;
+
+mod save;
+pub use save::*;
+
+mod update_last_used;
+pub use update_last_used::*;
@@ -0,0 +1,56 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::api_key::ApiKey, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{bson::doc, options::FindOptions};
+use tracing::{event, Level};
+
+pub async fn retrieve_all() -> Result<Vec<ApiKey>, VoyagerError> {
+  event!(Level::DEBUG, "Retrieving all API keys from database");
+
+  let future = async move {
+    let options = FindOptions::builder().sort(doc! { "created_at": -1 }).build();
+
+    let result = DB_CONTEXT
+      .api_keys
+      .find(None, options)
+      .await
+      .map_or_else(
+        |e| Err(VoyagerError::retrieve_api_keys(Box::new(e))),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled("repositories::api_keys::retrieve_all", future)
+    .await??;
+
+  event!(Level::DEBUG, "Done retrieving API keys");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn retrieve_api_keys(e: Error) -> Self {
+    Self::new(
+      "Failed to retrieve API keys".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+// Keys are kept once revoked, so that they still identify who did what
+pub async fn revoke(id: &str) -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Revoking API key of id {id} in database.");
+
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_revoke_api_key_id(Box::new(e), id))?;
+
+  let update = doc! {
+    "$set": {
+      "revoked_at": chrono::Utc::now().timestamp(),
+    }
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::api_keys::revoke",
+      DB_CONTEXT
+        .api_keys
+        .update_one(doc! { "_id": oid, "revoked_at": null }, update, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::revoke_api_key_mongo(Box::new(e), id)),
+    |r| {
+      if r.matched_count == 0 {
+        Err(VoyagerError::revoke_api_key(id))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done revoking API key.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_revoke_api_key_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn revoke_api_key_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while revoking API key with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn revoke_api_key(id: &str) -> Self {
+    Self::new(
+      format!("API key not found or already revoked. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,35 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::api_key::ApiKey, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+pub async fn save(api_key: ApiKey) -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Saving API key {} to database", &api_key.name);
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::api_keys::save",
+      DB_CONTEXT.api_keys.insert_one(api_key, None),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::save_api_key(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done saving API key.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn save_api_key(e: Error) -> Self {
+    Self::new(
+      "Failed to save API key".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,44 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn update_last_used(id: ObjectId, last_used_at: i64) -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Updating last use of API key of id {id} in database.");
+
+  let update = doc! {
+    "$set": {
+      "last_used_at": last_used_at,
+    }
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::api_keys::update_last_used",
+      DB_CONTEXT
+        .api_keys
+        .update_one(doc! { "_id": id }, update, None),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::update_last_used(Box::new(e), id))?;
+
+  event!(Level::DEBUG, "Done updating last use of API key.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn update_last_used(e: Error, id: ObjectId) -> Self {
+    Self::new(
+      format!("Failure while updating last use of API key with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,87 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{
+    model::audit_log::AuditLog,
+    other::{audit_log_filter::AuditLogFilter, voyager_error::VoyagerError},
+  },
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{
+  bson::{doc, Document},
+  options::FindOptions,
+};
+use tracing::{event, Level};
+
+// Newest first
+pub async fn find(filter: AuditLogFilter) -> Result<Vec<AuditLog>, VoyagerError> {
+  event!(Level::DEBUG, "Finding audit logs matching {filter:?} in database");
+
+  let mut document = Document::new();
+  if let Some(api_key_id) = filter.api_key_id {
+    document.insert("api_key_id", api_key_id);
+  }
+  if let Some(action) = filter.action {
+    document.insert("action", action);
+  }
+  if let Some(target_id) = filter.target_id {
+    document.insert("target_id", target_id);
+  }
+  let mut created_at = Document::new();
+  if let Some(since) = filter.since {
+    created_at.insert("$gte", since);
+  }
+  if let Some(until) = filter.until {
+    created_at.insert("$lte", until);
+  }
+  if !created_at.is_empty() {
+    document.insert("created_at", created_at);
+  }
+
+  let future = async move {
+    let options = FindOptions::builder()
+      .sort(doc! { "created_at": -1 })
+      .limit(filter.limit)
+      .build();
+
+    let result = DB_CONTEXT
+      .audit_logs
+      .find(document, options)
+      .await
+      .map_or_else(
+        |e| Err(VoyagerError::find_audit_logs(Box::new(e))),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled("repositories::audit_logs::find", future)
+    .await??;
+
+  event!(Level::DEBUG, "Done finding audit logs");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn find_audit_logs(e: Error) -> Self {
+    Self::new(
+      "Failed to retrieve audit logs".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,5 @@
+mod find;
+pub use find::*;
+
+mod save;
+pub use save::*;
@@ -0,0 +1,35 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::audit_log::AuditLog, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+pub async fn save(audit_log: AuditLog) -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Saving audit log of action {} to database", &audit_log.action);
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::audit_logs::save",
+      DB_CONTEXT.audit_logs.insert_one(audit_log, None),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::save_audit_log(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done saving audit log.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn save_audit_log(e: Error) -> Self {
+    Self::new(
+      "Failed to save audit log".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,58 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId, Bson};
+use tracing::{event, Level};
+
+// Forgets a deleted image in every build of the deployment that produced or reused it
+pub async fn clear_image(deployment_id: &str, image_id: &str) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Clearing image {image_id} from builds of deployment with id {deployment_id} in database."
+  );
+
+  let oid = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_clear_image_id(Box::new(e), deployment_id))?;
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::builds::clear_image",
+      DB_CONTEXT.builds.update_many(
+        doc! { "deployment_id": oid, "image_id": image_id },
+        doc! { "$set": { "image_id": Bson::Null } },
+        None,
+      ),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::clear_image(Box::new(e), image_id))?;
+
+  event!(Level::DEBUG, "Done clearing image.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn invalid_clear_image_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn clear_image(e: Error, image_id: &str) -> Self {
+    Self::new(
+      format!("Failure while clearing image '{image_id}' from builds"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
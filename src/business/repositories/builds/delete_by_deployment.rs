@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn delete_by_deployment(deployment_id: &str) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Deleting builds of deployment with id {deployment_id} from database."
+  );
+
+  let oid = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_delete_builds_id(Box::new(e), deployment_id))?;
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::builds::delete_by_deployment",
+      DB_CONTEXT
+        .builds
+        .delete_many(doc! { "deployment_id": oid }, None),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::delete_builds(Box::new(e), deployment_id))?;
+
+  event!(Level::DEBUG, "Done deleting builds.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn invalid_delete_builds_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn delete_builds(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while deleting builds of deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
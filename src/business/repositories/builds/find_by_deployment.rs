@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::build::Build, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{
+  bson::{doc, oid::ObjectId},
+  options::FindOptions,
+};
+use tracing::{event, Level};
+
+// Newest first, without their output
+pub async fn find_by_deployment(deployment_id: &str) -> Result<Vec<Build>, VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Finding builds of deployment with id {} in database",
+    &deployment_id
+  );
+
+  let oid = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_builds_deployment_id(Box::new(e), deployment_id))?;
+
+  let future = async move {
+    let options = FindOptions::builder()
+      .sort(doc! { "started_at": -1 })
+      .projection(doc! { "output": 0 })
+      .build();
+
+    let result = DB_CONTEXT
+      .builds
+      .find(doc! { "deployment_id": oid }, options)
+      .await
+      .map_or_else(
+        |e| Err(VoyagerError::find_by_deployment(Box::new(e))),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled("repositories::builds::find_by_deployment", future)
+    .await??;
+
+  event!(Level::DEBUG, "Done finding builds");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn invalid_builds_deployment_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn find_by_deployment(e: Error) -> Self {
+    Self::new(
+      "Failed to retrieve builds".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::build::Build, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn find_by_id(deployment_id: &str, id: &str) -> Result<Build, VoyagerError> {
+  event!(Level::DEBUG, "Finding build with id {} in database", &id);
+
+  let deployment_object_id = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_build_id(Box::new(e), deployment_id))?;
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_build_id(Box::new(e), id))?;
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::builds::find_by_id",
+      DB_CONTEXT.builds.find_one(doc! { "_id": oid, "deployment_id": deployment_object_id }, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::find_build(Box::new(e), id)),
+    |r| r.ok_or_else(|| VoyagerError::null_build(id)),
+  )?;
+
+  event!(Level::DEBUG, "Done finding build");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn invalid_build_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn find_build(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while finding build by id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn null_build(id: &str) -> Self {
+    Self::new(
+      format!("Build not found. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,14 @@
+mod clear_image;
+pub use clear_image::*;
+
+mod delete_by_deployment;
+pub use delete_by_deployment::*;
+
+mod find_by_deployment;
+pub use find_by_deployment::*;
+
+mod find_by_id;
+pub use find_by_id::*;
+
+mod save;
+pub use save::*;
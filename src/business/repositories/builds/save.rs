@@ -0,0 +1,39 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::build::Build, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+pub async fn save(build: Build) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Saving build for deployment with id {} to database",
+    &build.deployment_id
+  );
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::builds::save",
+      DB_CONTEXT.builds.insert_one(build, None),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::save_build(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done saving build.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn save_build(e: Error) -> Self {
+    Self::new(
+      "Failed to save build".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
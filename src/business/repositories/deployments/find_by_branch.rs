@@ -0,0 +1,65 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::deployment::Deployment, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::doc;
+use tracing::{event, Level};
+
+// Deployments of any repository tracking a branch of that name
+pub async fn find_by_branch(branch: &str) -> Result<Vec<Deployment>, VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Finding deployments with branch {} in database",
+    branch
+  );
+
+  let document = doc! { "branch": branch };
+  let future = async move {
+    let result = DB_CONTEXT
+      .deployments
+      .find(document, None)
+      .await
+      .map_or_else(
+        |e| Err(Error::from(e)),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok::<Vec<Deployment>, Error>(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::deployments::find_by_branch",
+      future,
+    )
+    .await?;
+
+  let result = result.map_err(|e| VoyagerError::find_mongo_branch(e, branch))?;
+
+  event!(Level::DEBUG, "Done finding deployments");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn find_mongo_branch(e: Error, branch: &str) -> Self {
+    Self::new(
+      format!("Failure while finding deployments by branch {branch}"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -7,27 +7,48 @@ use axum::http::StatusCode;
 use mongodb::bson::doc;
 use tracing::{event, Level};
 
-pub async fn find_by_repo_branch(repo_url: &str, branch: &str) -> Result<Option<Deployment>, VoyagerError> {
+pub async fn find_by_repo_branch(repo_url: &str, branch: &str) -> Result<Vec<Deployment>, VoyagerError> {
   event!(
     Level::DEBUG,
-    "Finding deployment with repo url {} and branch {} in database",
+    "Finding deployments with repo url {} and branch {} in database",
     repo_url,
     branch
   );
 
+  let document = doc! { "repo_url": repo_url, "branch": branch };
+  let future = async move {
+    let result = DB_CONTEXT
+      .deployments
+      .find(document, None)
+      .await
+      .map_or_else(
+        |e| Err(Error::from(e)),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok::<Vec<Deployment>, Error>(result.await)
+  };
+
   let result = REPOSITORIES_RUNTIME
     .spawn_handled(
       "repositories::deployments::find_by_repo_branch",
-      DB_CONTEXT.deployments.find_one(doc! { "repo_url": repo_url, "branch": branch }, None),
+      future,
     )
     .await?;
 
-  let result = result.map_or_else(
-    |e| Err(VoyagerError::find_mongo_repo_branch(Box::new(e), repo_url, branch)),
-    |r| Ok(r),
-  )?;
+  let result = result.map_err(|e| VoyagerError::find_mongo_repo_branch(e, repo_url, branch))?;
 
-  event!(Level::DEBUG, "Done finding deployment");
+  event!(Level::DEBUG, "Done finding deployments");
 
   Ok(result)
 }
@@ -35,7 +56,7 @@ pub async fn find_by_repo_branch(repo_url: &str, branch: &str) -> Result<Option<
 impl VoyagerError {
   fn find_mongo_repo_branch(e: Error, repo_url: &str, branch: &str) -> Self {
     Self::new(
-      format!("Failure while finding deployment by repo url '{repo_url}' and branch {branch}"),
+      format!("Failure while finding deployments by repo url '{repo_url}' and branch {branch}"),
       StatusCode::INTERNAL_SERVER_ERROR,
       false,
       Some(e),
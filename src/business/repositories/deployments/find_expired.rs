@@ -0,0 +1,61 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::deployment::Deployment, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::doc;
+use tracing::{event, Level};
+
+// Deployments whose expiry is at or before the given Unix timestamp
+pub async fn find_expired(now: i64) -> Result<Vec<Deployment>, VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Finding deployments expired at {now} in database"
+  );
+
+  let document = doc! { "expires_at": { "$ne": null, "$lte": now } };
+  let future = async move {
+    let result = DB_CONTEXT
+      .deployments
+      .find(document, None)
+      .await
+      .map_or_else(
+        |e| Err(Error::from(e)),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok::<Vec<Deployment>, Error>(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled("repositories::deployments::find_expired", future)
+    .await?;
+
+  let result = result.map_err(VoyagerError::find_expired)?;
+
+  event!(Level::DEBUG, "Done finding expired deployments");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn find_expired(e: Error) -> Self {
+    Self::new(
+      "Failure while finding expired deployments".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
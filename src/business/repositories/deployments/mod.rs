@@ -14,4 +14,28 @@ mod find_by_name;
 pub use find_by_name::*;
 
 mod find_by_repo_branch;
-pub use find_by_repo_branch::*;
\ No newline at end of file
+pub use find_by_repo_branch::*;
+
+mod find_by_branch;
+pub use find_by_branch::*;
+
+mod find_expired;
+pub use find_expired::*;
+
+mod transition_state;
+pub use transition_state::*;
+
+mod update_expires_at;
+pub use update_expires_at::*;
+
+mod update_build_options;
+pub use update_build_options::*;
+
+mod update_resource_limits;
+pub use update_resource_limits::*;
+
+mod update_release;
+pub use update_release::*;
+
+mod update_state;
+pub use update_state::*;
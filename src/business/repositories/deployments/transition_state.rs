@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{
+    model::deployment::{Deployment, DeploymentState},
+    other::voyager_error::VoyagerError,
+  },
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{
+  bson::{doc, oid::ObjectId},
+  options::{FindOneAndUpdateOptions, ReturnDocument},
+};
+use tracing::{event, Level};
+
+// Moves a deployment to `state` only if it is in one of the `from` states, in a single update, so that two
+// requests can't both start working on it. Returns the deployment as it was before, or None if it was in
+// another state
+pub async fn transition_state(
+  id: &str,
+  from: &[DeploymentState],
+  state: DeploymentState,
+) -> Result<Option<Deployment>, VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Updating state of deployment of id {id} from {from:?} to {state} in database."
+  );
+
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_transition_state_id(Box::new(e), id))?;
+
+  // Deployments saved before states were tracked have none, and read back as Deployed
+  let legacy = from.contains(&DeploymentState::Deployed);
+  let from = from.iter().map(ToString::to_string).collect::<Vec<_>>();
+  let filter = if legacy {
+    doc! {
+      "_id": oid,
+      "$or": [ { "state": { "$in": from } }, { "state": { "$exists": false } } ],
+    }
+  } else {
+    doc! { "_id": oid, "state": { "$in": from } }
+  };
+  let update = doc! {
+    "$set": {
+      "state": state.to_string(),
+      "updated_at": chrono::Utc::now().timestamp(),
+    }
+  };
+  let options = FindOneAndUpdateOptions::builder()
+    .return_document(ReturnDocument::Before)
+    .build();
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::deployments::transition_state",
+      DB_CONTEXT
+        .deployments
+        .find_one_and_update(filter, update, options),
+    )
+    .await?
+    .map_err(|e| VoyagerError::transition_state_mongo(Box::new(e), id))?;
+
+  event!(Level::DEBUG, "Done updating deployment state.");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn invalid_transition_state_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn transition_state_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while updating state of deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
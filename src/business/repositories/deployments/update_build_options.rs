@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::deployment::BuildOptions, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{self, doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn update_build_options(id: &str, build_options: &BuildOptions) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Updating build options of deployment of id {id} in database."
+  );
+
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_update_build_options_id(Box::new(e), id))?;
+  let build_options = bson::to_bson(build_options)
+    .map_err(|e| VoyagerError::update_build_options_mongo(Box::new(e), id))?;
+
+  let update = doc! {
+    "$set": {
+      "build_options": build_options,
+      "updated_at": chrono::Utc::now().timestamp(),
+    }
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::deployments::update_build_options",
+      DB_CONTEXT
+        .deployments
+        .update_one(doc! { "_id": oid }, update, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::update_build_options_mongo(Box::new(e), id)),
+    |r| {
+      if r.matched_count == 0 {
+        Err(VoyagerError::update_build_options(id))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done updating deployment build options.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_update_build_options_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_build_options_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while updating build options of deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_build_options(id: &str) -> Self {
+    Self::new(
+      format!("Deployment not found. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn update_expires_at(id: &str, expires_at: Option<i64>) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Updating expiry of deployment of id {id} to {expires_at:?} in database."
+  );
+
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_update_expires_at_id(Box::new(e), id))?;
+
+  let update = doc! {
+    "$set": {
+      "expires_at": expires_at,
+      "updated_at": chrono::Utc::now().timestamp(),
+    }
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::deployments::update_expires_at",
+      DB_CONTEXT
+        .deployments
+        .update_one(doc! { "_id": oid }, update, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::update_expires_at_mongo(Box::new(e), id)),
+    |r| {
+      if r.matched_count == 0 {
+        Err(VoyagerError::update_expires_at(id))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done updating deployment expiry.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_update_expires_at_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_expires_at_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while updating expiry of deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_expires_at(id: &str) -> Self {
+    Self::new(
+      format!("Deployment not found. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,75 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::deployment::Deployment, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, to_bson};
+use tracing::{event, Level};
+
+// Only sets what a deployment pipeline produces. The rest of the deployment, such as its expiry or build options,
+// can be changed while it runs, and must not be overwritten with what was read when it started
+pub async fn update_release(deployment: &Deployment) -> Result<(), VoyagerError> {
+  let id = deployment.id.to_hex();
+  event!(
+    Level::DEBUG,
+    "Updating release of deployment of id {id} in database."
+  );
+
+  let commit = to_bson(&deployment.commit).map_err(|e| VoyagerError::update_release_mongo(Box::new(e), &id))?;
+  let update = doc! {
+    "$set": {
+      "container_id": &deployment.container_id,
+      "dns_record_id": &deployment.dns_record_id,
+      "image_id": &deployment.image_id,
+      "commit": commit,
+      "state": deployment.state.to_string(),
+      "failure_reason": &deployment.failure_reason,
+      "updated_at": deployment.updated_at,
+    }
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::deployments::update_release",
+      DB_CONTEXT
+        .deployments
+        .update_one(doc! { "_id": deployment.id }, update, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::update_release_mongo(Box::new(e), &id)),
+    |r| {
+      if r.matched_count == 0 {
+        Err(VoyagerError::update_release(&id))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done updating deployment release.");
+
+  result
+}
+
+impl VoyagerError {
+  fn update_release_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while updating deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_release(id: &str) -> Self {
+    Self::new(
+      format!("Deployment not found. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::deployment::ResourceLimits, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{self, doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn update_resource_limits(id: &str, resource_limits: &ResourceLimits) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Updating resource limits of deployment of id {id} in database."
+  );
+
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_update_resource_limits_id(Box::new(e), id))?;
+  let resource_limits = bson::to_bson(resource_limits)
+    .map_err(|e| VoyagerError::update_resource_limits_mongo(Box::new(e), id))?;
+
+  let update = doc! {
+    "$set": {
+      "resource_limits": resource_limits,
+      "updated_at": chrono::Utc::now().timestamp(),
+    }
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::deployments::update_resource_limits",
+      DB_CONTEXT
+        .deployments
+        .update_one(doc! { "_id": oid }, update, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::update_resource_limits_mongo(Box::new(e), id)),
+    |r| {
+      if r.matched_count == 0 {
+        Err(VoyagerError::update_resource_limits(id))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done updating deployment resource limits.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_update_resource_limits_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_resource_limits_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while updating resource limits of deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_resource_limits(id: &str) -> Self {
+    Self::new(
+      format!("Deployment not found. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
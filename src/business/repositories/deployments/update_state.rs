@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::deployment::DeploymentState, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn update_state(
+  id: &str,
+  state: DeploymentState,
+  failure_reason: Option<String>,
+) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Updating state of deployment of id {id} to {state} in database."
+  );
+
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_update_state_id(Box::new(e), id))?;
+
+  let update = doc! {
+    "$set": {
+      "state": state.to_string(),
+      "failure_reason": failure_reason,
+      "updated_at": chrono::Utc::now().timestamp(),
+    }
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::deployments::update_state",
+      DB_CONTEXT
+        .deployments
+        .update_one(doc! { "_id": oid }, update, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::update_state_mongo(Box::new(e), id)),
+    |r| {
+      if r.matched_count == 0 {
+        Err(VoyagerError::update_state(id))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done updating deployment state.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_update_state_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_state_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while updating state of deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn update_state(id: &str) -> Self {
+    Self::new(
+      format!("Deployment not found. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn delete(deployment_id: &str, name: &str) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Deleting environment variable {name} of deployment with id {deployment_id} from database."
+  );
+
+  let oid = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_delete_environment_id(Box::new(e), deployment_id))?;
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::environment_variables::delete",
+      DB_CONTEXT
+        .environment_variables
+        .delete_one(doc! { "deployment_id": oid, "name": name }, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::delete_environment_mongo(Box::new(e), name)),
+    |r| {
+      if r.deleted_count == 0 {
+        Err(VoyagerError::delete_environment(name))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done deleting environment variable.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_delete_environment_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn delete_environment_mongo(e: Error, name: &str) -> Self {
+    Self::new(
+      format!("Failure while deleting environment variable '{name}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn delete_environment(name: &str) -> Self {
+    Self::new(
+      format!("Environment variable not found. Name: '{name}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn delete_by_deployment(deployment_id: &str) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Deleting environment variables of deployment with id {deployment_id} from database."
+  );
+
+  let oid = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_delete_environment_variables_id(Box::new(e), deployment_id))?;
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::environment_variables::delete_by_deployment",
+      DB_CONTEXT
+        .environment_variables
+        .delete_many(doc! { "deployment_id": oid }, None),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::delete_environment_variables(Box::new(e), deployment_id))?;
+
+  event!(Level::DEBUG, "Done deleting environment variables.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn invalid_delete_environment_variables_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn delete_environment_variables(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while deleting environment variables of deployment with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::environment_variable::EnvironmentVariable, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{
+  bson::{doc, oid::ObjectId},
+  options::FindOptions,
+};
+use tracing::{event, Level};
+
+pub async fn find_by_deployment(deployment_id: &str) -> Result<Vec<EnvironmentVariable>, VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Finding environment variables of deployment with id {} in database",
+    &deployment_id
+  );
+
+  let oid = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_environment_deployment_id(Box::new(e), deployment_id))?;
+
+  let future = async move {
+    let options = FindOptions::builder().sort(doc! { "name": 1 }).build();
+
+    let result = DB_CONTEXT
+      .environment_variables
+      .find(doc! { "deployment_id": oid }, options)
+      .await
+      .map_or_else(
+        |e| Err(VoyagerError::find_environment(Box::new(e))),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled("repositories::environment_variables::find_by_deployment", future)
+    .await??;
+
+  event!(Level::DEBUG, "Done finding environment variables");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn invalid_environment_deployment_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn find_environment(e: Error) -> Self {
+    Self::new(
+      "Failed to retrieve environment variables".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
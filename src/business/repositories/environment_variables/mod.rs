@@ -0,0 +1,11 @@
+mod delete;
+pub use delete::*;
+
+mod delete_by_deployment;
+pub use delete_by_deployment::*;
+
+mod find_by_deployment;
+pub use find_by_deployment::*;
+
+mod upsert;
+pub use upsert::*;
@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{
+  bson::{doc, oid::ObjectId},
+  options::UpdateOptions,
+};
+use tracing::{event, Level};
+
+// Creates the variable, or replaces the value of the one with the same name
+pub async fn upsert(deployment_id: &str, name: &str, value: String, secret: bool) -> Result<(), VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Saving environment variable {name} of deployment with id {deployment_id} to database"
+  );
+
+  let oid = ObjectId::from_str(deployment_id)
+    .map_err(|e| VoyagerError::invalid_upsert_environment_id(Box::new(e), deployment_id))?;
+
+  let update = doc! {
+    "$set": {
+      "value": value,
+      "secret": secret,
+      "updated_at": chrono::Utc::now().timestamp(),
+    }
+  };
+  let options = UpdateOptions::builder().upsert(true).build();
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::environment_variables::upsert",
+      DB_CONTEXT
+        .environment_variables
+        .update_one(doc! { "deployment_id": oid, "name": name }, update, options),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::upsert_environment(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done saving environment variable.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn invalid_upsert_environment_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn upsert_environment(e: Error) -> Self {
+    Self::new(
+      "Failed to save environment variable".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
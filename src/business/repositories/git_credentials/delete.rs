@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::bson::{doc, oid::ObjectId};
+use tracing::{event, Level};
+
+pub async fn delete(id: &str) -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Deleting git credential of id {id} from database.");
+
+  let oid = ObjectId::from_str(id)
+    .map_err(|e| VoyagerError::invalid_delete_git_credential_id(Box::new(e), id))?;
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::git_credentials::delete",
+      DB_CONTEXT
+        .git_credentials
+        .delete_one(doc! { "_id": oid }, None),
+    )
+    .await?;
+
+  let result = result.map_or_else(
+    |e| Err(VoyagerError::delete_git_credential_mongo(Box::new(e), id)),
+    |r| {
+      if r.deleted_count == 0 {
+        Err(VoyagerError::delete_git_credential(id))
+      } else {
+        Ok(())
+      }
+    },
+  );
+
+  event!(Level::DEBUG, "Done deleting git credential.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_delete_git_credential_id(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Invalid Bson id '{id}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn delete_git_credential_mongo(e: Error, id: &str) -> Self {
+    Self::new(
+      format!("Failure while deleting git credential with id '{id}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn delete_git_credential(id: &str) -> Self {
+    Self::new(
+      format!("Git credential not found. Id: '{id}'"),
+      StatusCode::NOT_FOUND,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,70 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{
+    model::git_credential::{GitCredential, GitCredentialKind},
+    other::voyager_error::VoyagerError,
+  },
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{
+  bson::{doc, to_bson},
+  options::FindOptions,
+};
+use tracing::{event, Level};
+
+// The credentials of the owner along with the ones of the whole host, most recent first
+pub async fn find_by_host(host: &str, owner: &str, kind: GitCredentialKind) -> Result<Vec<GitCredential>, VoyagerError> {
+  event!(Level::DEBUG, "Finding {kind} git credentials for {host}/{owner} in database");
+
+  let kind = to_bson(&kind).map_err(|e| VoyagerError::find_git_credentials(Box::new(e)))?;
+  let filter = doc! {
+    "host": host,
+    "owner": { "$in": [owner, null] },
+    "kind": kind,
+  };
+
+  let future = async move {
+    let options = FindOptions::builder().sort(doc! { "created_at": -1 }).build();
+
+    let result = DB_CONTEXT
+      .git_credentials
+      .find(filter, options)
+      .await
+      .map_or_else(
+        |e| Err(VoyagerError::find_git_credentials(Box::new(e))),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled("repositories::git_credentials::find_by_host", future)
+    .await??;
+
+  event!(Level::DEBUG, "Done finding git credentials");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn find_git_credentials(e: Error) -> Self {
+    Self::new(
+      "Failure while finding git credentials".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
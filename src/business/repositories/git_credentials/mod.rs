@@ -0,0 +1,11 @@
+mod delete;
+pub use delete::*;
+
+mod find_by_host;
+pub use find_by_host::*;
+
+mod retrieve_all;
+pub use retrieve_all::*;
+
+mod save;
+pub use save::*;
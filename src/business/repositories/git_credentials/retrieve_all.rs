@@ -0,0 +1,56 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::git_credential::GitCredential, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use mongodb::{bson::doc, options::FindOptions};
+use tracing::{event, Level};
+
+pub async fn retrieve_all() -> Result<Vec<GitCredential>, VoyagerError> {
+  event!(Level::DEBUG, "Retrieving all git credentials from database");
+
+  let future = async move {
+    let options = FindOptions::builder().sort(doc! { "host": 1, "owner": 1, "created_at": -1 }).build();
+
+    let result = DB_CONTEXT
+      .git_credentials
+      .find(None, options)
+      .await
+      .map_or_else(
+        |e| Err(VoyagerError::retrieve_git_credentials(Box::new(e))),
+        |mut cursor| {
+          Ok(async move {
+            let mut list = Vec::new();
+            while cursor.advance().await.unwrap_or(false) {
+              if let Ok(crr) = cursor.deserialize_current() {
+                list.push(crr);
+              }
+            }
+            list
+          })
+        },
+      )?;
+
+    Ok(result.await)
+  };
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled("repositories::git_credentials::retrieve_all", future)
+    .await??;
+
+  event!(Level::DEBUG, "Done retrieving git credentials");
+
+  Ok(result)
+}
+
+impl VoyagerError {
+  fn retrieve_git_credentials(e: Error) -> Self {
+    Self::new(
+      "Failed to retrieve git credentials".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -0,0 +1,35 @@
+use crate::{
+  business::repositories::{DB_CONTEXT, REPOSITORIES_RUNTIME},
+  types::{model::git_credential::GitCredential, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+pub async fn save(git_credential: GitCredential) -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Saving git credential {} to database", &git_credential.name);
+
+  let result = REPOSITORIES_RUNTIME
+    .spawn_handled(
+      "repositories::git_credentials::save",
+      DB_CONTEXT.git_credentials.insert_one(git_credential, None),
+    )
+    .await?;
+
+  result.map_err(|e| VoyagerError::save_git_credential(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done saving git credential.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn save_git_credential(e: Error) -> Self {
+    Self::new(
+      "Failed to save git credential".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -1,4 +1,9 @@
+pub mod api_keys;
+pub mod audit_logs;
+pub mod builds;
 pub mod deployments;
+pub mod environment_variables;
+pub mod git_credentials;
 
 use futures::executor;
 use lazy_static::lazy_static;
@@ -7,12 +12,17 @@ use tokio::runtime::Runtime;
 
 use crate::{
   configs::environment::{MONGO_CONN_STR, MONGO_DB_NAME},
-  types::model::deployment::Deployment,
+  types::model::{api_key::ApiKey, audit_log::AuditLog, build::Build, deployment::Deployment, environment_variable::EnvironmentVariable, git_credential::GitCredential},
   utils::ExpectError,
 };
 
 pub struct DbContext {
   pub deployments: mongodb::Collection<Deployment>,
+  pub builds: mongodb::Collection<Build>,
+  pub environment_variables: mongodb::Collection<EnvironmentVariable>,
+  pub api_keys: mongodb::Collection<ApiKey>,
+  pub audit_logs: mongodb::Collection<AuditLog>,
+  pub git_credentials: mongodb::Collection<GitCredential>,
 }
 
 impl DbContext {
@@ -24,8 +34,13 @@ impl DbContext {
       .expect_error(|e| format!("Failed to connect to MongoDB: {e}"));
     let db = client.database(&MONGO_DB_NAME);
     let deployments = db.collection("Deployments");
+    let builds = db.collection("Builds");
+    let environment_variables = db.collection("EnvironmentVariables");
+    let api_keys = db.collection("ApiKeys");
+    let audit_logs = db.collection("AuditLog");
+    let git_credentials = db.collection("GitCredentials");
 
-    Self { deployments }
+    Self { deployments, builds, environment_variables, api_keys, audit_logs, git_credentials }
   }
 }
 
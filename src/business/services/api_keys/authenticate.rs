@@ -0,0 +1,78 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use super::key::hash_key;
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  configs::environment::API_KEY,
+  types::{
+    model::api_key::ApiKeyScope,
+    other::{api_key_identity::ApiKeyIdentity, voyager_error::VoyagerError},
+  },
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+// Last use is only recorded once per interval, to avoid a write on every request
+const LAST_USED_INTERVAL: i64 = 60;
+
+pub async fn authenticate(key: String) -> Result<ApiKeyIdentity, VoyagerError> {
+  if key.is_empty() {
+    return Err(VoyagerError::invalid_api_key());
+  }
+
+  // The key of the environment has every scope, so that the first keys can be created with it.
+  // Compared by hash, so that the time taken doesn't tell how much of it was guessed
+  let hash = hash_key(&key);
+  if API_KEY.as_ref().is_some_and(|api_key| hash_key(api_key) == hash) {
+    return Ok(ApiKeyIdentity {
+      id: None,
+      name: "API_KEY".to_string(),
+      scopes: vec![ApiKeyScope::Admin],
+    });
+  }
+
+  SERVICES_RUNTIME
+    .spawn_handled("services::api_keys::authenticate", async move {
+      let api_key = repositories::api_keys::find_by_hash(&hash)
+        .await?
+        .ok_or_else(VoyagerError::invalid_api_key)?;
+
+      let now = chrono::Utc::now().timestamp();
+      if api_key.expires_at.is_some_and(|expires_at| expires_at <= now) {
+        return Err(VoyagerError::expired_api_key());
+      }
+
+      if api_key.last_used_at.is_none_or(|last_used_at| now - last_used_at >= LAST_USED_INTERVAL) {
+        if let Err(e) = repositories::api_keys::update_last_used(api_key.id, now).await {
+          event!(Level::WARN, "Failed to record use of API key {}: {}", api_key.id, e.message);
+        }
+      }
+
+      Ok(ApiKeyIdentity {
+        id: Some(api_key.id.to_hex()),
+        name: api_key.name,
+        scopes: api_key.scopes,
+      })
+    })
+    .await?
+}
+
+impl VoyagerError {
+  fn invalid_api_key() -> Self {
+    Self::new(
+      "Invalid API Key".to_string(),
+      StatusCode::UNAUTHORIZED,
+      false,
+      None,
+    )
+  }
+
+  fn expired_api_key() -> Self {
+    Self::new(
+      "Expired API Key".to_string(),
+      StatusCode::UNAUTHORIZED,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,89 @@
+use axum::http::StatusCode;
+use mongodb::bson::oid::ObjectId;
+use tracing::{event, Level};
+
+use super::key::{generate_key, hash_key};
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{
+    model::api_key::{ApiKey, ApiKeyScope},
+    other::voyager_error::VoyagerError,
+  },
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+// Returns the saved key along with the key itself, which is not stored and can't be retrieved later
+pub async fn create(name: String, scopes: Vec<ApiKeyScope>, ttl: Option<i64>) -> Result<(ApiKey, String), VoyagerError> {
+  event!(Level::INFO, "Creating API key {name} with scopes {scopes:?}");
+
+  let name = name.trim().to_string();
+  if name.is_empty() {
+    return Err(VoyagerError::empty_api_key_name());
+  }
+  if scopes.is_empty() {
+    return Err(VoyagerError::empty_api_key_scopes());
+  }
+  if ttl.is_some_and(|ttl| ttl <= 0) {
+    return Err(VoyagerError::invalid_api_key_ttl());
+  }
+
+  let key = generate_key().ok_or_else(VoyagerError::generate_api_key)?;
+  let now = chrono::Utc::now().timestamp();
+  let api_key = ApiKey {
+    id: ObjectId::new(),
+    name,
+    key_hash: hash_key(&key),
+    scopes,
+    created_at: now,
+    expires_at: ttl.map(|ttl| now.saturating_add(ttl)),
+    last_used_at: None,
+    revoked_at: None,
+  };
+
+  let saved = api_key.clone();
+  SERVICES_RUNTIME
+    .spawn_handled("services::api_keys::create", repositories::api_keys::save(saved))
+    .await??;
+
+  event!(Level::INFO, "API key created. Id: {}", api_key.id);
+
+  Ok((api_key, key))
+}
+
+impl VoyagerError {
+  fn empty_api_key_name() -> Self {
+    Self::new(
+      "API key name can't be empty".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn empty_api_key_scopes() -> Self {
+    Self::new(
+      "API key needs at least one scope".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn invalid_api_key_ttl() -> Self {
+    Self::new(
+      "API key ttl must be positive".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn generate_api_key() -> Self {
+    Self::new(
+      "Failed to generate API key".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      None,
+    )
+  }
+}
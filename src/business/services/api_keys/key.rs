@@ -0,0 +1,16 @@
+use ring::rand::{SecureRandom, SystemRandom};
+use sha2::{Digest, Sha256};
+
+// Keys are prefixed so that they can be told apart, e.g. by secret scanners
+const KEY_PREFIX: &str = "voyager_";
+
+pub(super) fn generate_key() -> Option<String> {
+  let mut key = [0u8; 32];
+  SystemRandom::new().fill(&mut key).ok()?;
+
+  Some(format!("{KEY_PREFIX}{}", hex::encode(key)))
+}
+
+pub(super) fn hash_key(key: &str) -> String {
+  hex::encode(Sha256::digest(key.as_bytes()))
+}
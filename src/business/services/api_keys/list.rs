@@ -0,0 +1,19 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{model::api_key::ApiKey, other::voyager_error::VoyagerError},
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+pub async fn list() -> Result<Vec<ApiKey>, VoyagerError> {
+  event!(Level::INFO, "Retrieving API keys");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::api_keys::list", repositories::api_keys::retrieve_all())
+    .await?;
+
+  event!(Level::DEBUG, "Done retrieving API keys.");
+
+  result
+}
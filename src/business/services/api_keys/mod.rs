@@ -0,0 +1,10 @@
+mod authenticate;
+mod create;
+mod key;
+mod list;
+mod revoke;
+
+pub use authenticate::*;
+pub use create::*;
+pub use list::*;
+pub use revoke::*;
@@ -0,0 +1,21 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+pub async fn revoke(id: String) -> Result<(), VoyagerError> {
+  event!(Level::INFO, "Revoking API key. Id: {id}");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::api_keys::revoke", async move {
+      repositories::api_keys::revoke(&id).await
+    })
+    .await?;
+
+  event!(Level::INFO, "Done revoking API key.");
+
+  result
+}
@@ -0,0 +1,43 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{
+    model::audit_log::AuditLog,
+    other::{audit_log_filter::AuditLogFilter, voyager_error::VoyagerError},
+  },
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 1000;
+
+pub async fn list(mut filter: AuditLogFilter) -> Result<Vec<AuditLog>, VoyagerError> {
+  event!(Level::INFO, "Retrieving audit logs matching {filter:?}");
+
+  match filter.limit {
+    Some(limit) if !(1..=MAX_LIMIT).contains(&limit) => return Err(VoyagerError::invalid_audit_limit()),
+    Some(_) => {},
+    None => filter.limit = Some(DEFAULT_LIMIT),
+  }
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::audit::list", repositories::audit_logs::find(filter))
+    .await?;
+
+  event!(Level::DEBUG, "Done retrieving audit logs.");
+
+  result
+}
+
+impl VoyagerError {
+  fn invalid_audit_limit() -> Self {
+    Self::new(
+      format!("limit must be between 1 and {MAX_LIMIT}"),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,5 @@
+mod list;
+mod record;
+
+pub use list::*;
+pub use record::*;
@@ -0,0 +1,29 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::model::audit_log::AuditLog,
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+// Best effort: a call that could not be recorded is only logged, as it has already been answered
+pub async fn record(audit_log: AuditLog) {
+  event!(
+    Level::INFO,
+    "{} by {}: {} ({})",
+    audit_log.action,
+    audit_log.api_key_name,
+    audit_log.path,
+    audit_log.outcome.status
+  );
+
+  let action = audit_log.action.clone();
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::audit::record", repositories::audit_logs::save(audit_log))
+    .await
+    .and_then(|r| r);
+
+  if let Err(e) = result {
+    event!(Level::WARN, "Failed to record {action} in the audit log: {}", e.message);
+  }
+}
@@ -0,0 +1,85 @@
+use std::time::{Duration, Instant};
+
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::configs::environment::HEALTH_CHECK_INTERVAL;
+use crate::modules::{docker, notifications};
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::notification::{Notification, NotificationEvent};
+use crate::types::other::voyager_error::VoyagerError;
+
+// Time given to a restarted container before checking whether it stayed up
+const RESTART_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+// Periodically checks every deployed deployment, restarting the ones whose container is not running.
+// Deployments that stay down are marked as stopped and reported.
+pub async fn health_monitor() {
+  event!(Level::INFO, "Starting deployment health monitor.");
+
+  loop {
+    let start = Instant::now();
+    if let Err(e) = tick().await {
+      event!(Level::WARN, "Failed to check deployments health: {}", e.message);
+    }
+
+    // Sleeps for no less than the configured interval, and for at least 95% of the time:
+    // elapsed / (delay + elapsed) <= 5%  =>  delay >= 19 * elapsed
+    let delay = Duration::from_secs(*HEALTH_CHECK_INTERVAL).max(start.elapsed() * 19);
+    tokio::time::sleep(delay).await;
+  }
+}
+
+async fn tick() -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Checking deployments health.");
+
+  let deployments = repositories::deployments::retrieve_all(None, None).await?;
+  for deployment in deployments {
+    if !matches!(deployment.state, DeploymentState::Deployed) {
+      continue;
+    }
+
+    let id = deployment.id.to_hex();
+    let name = deployment.container_name.clone();
+
+    if is_running(&name).await {
+      continue;
+    }
+
+    // The list may be many seconds old by now, and a redeploy or rollback retires the container it replaces
+    match repositories::deployments::find_by_id(&id).await {
+      Ok(current) if matches!(current.state, DeploymentState::Deployed) => {},
+      _ => continue,
+    }
+
+    event!(Level::WARN, "Deployment {id} is not running. Restarting container {name}.");
+    let _ = docker::restart_container(name.clone()).await;
+    tokio::time::sleep(RESTART_GRACE_PERIOD).await;
+
+    if is_running(&name).await {
+      event!(Level::INFO, "Deployment {id} was restarted successfully.");
+      continue;
+    }
+
+    // Only if nothing started working on it meanwhile
+    let from = [DeploymentState::Deployed];
+    let Ok(Some(_)) = repositories::deployments::transition_state(&id, &from, DeploymentState::Stopped).await else {
+      continue;
+    };
+
+    event!(Level::WARN, "{} Deployment {id} has stopped.", deployment.mode);
+    let reason = "Container stopped running and could not be restarted".to_string();
+    let _ = repositories::deployments::update_state(&id, DeploymentState::Stopped, Some(reason.clone())).await;
+    notifications::notify(Notification::new(NotificationEvent::Down, &deployment, Some(reason))).await;
+  }
+
+  event!(Level::DEBUG, "Done checking deployments health.");
+
+  Ok(())
+}
+
+async fn is_running(container_name: &str) -> bool {
+  docker::is_container_running(container_name.to_string())
+    .await
+    .unwrap_or(false)
+}
@@ -0,0 +1,7 @@
+mod health_monitor;
+mod reaper;
+mod recover_interrupted;
+
+pub use health_monitor::*;
+pub use reaper::*;
+pub use recover_interrupted::*;
@@ -0,0 +1,51 @@
+use std::time::{Duration, Instant};
+
+use tracing::{event, Level};
+
+use crate::business::{repositories, services::deployments};
+use crate::configs::environment::REAPER_INTERVAL;
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::voyager_error::VoyagerError;
+
+// Periodically deletes every deployment whose expiry has lapsed, the same way the API does.
+// Deployments still being deployed are left for a later round.
+pub async fn reaper() {
+  event!(Level::INFO, "Starting expired deployments reaper.");
+
+  loop {
+    let start = Instant::now();
+    if let Err(e) = tick().await {
+      event!(Level::WARN, "Failed to reap expired deployments: {}", e.message);
+    }
+
+    // Same as the health monitor: sleeps for at least 95% of the time
+    let delay = Duration::from_secs(*REAPER_INTERVAL).max(start.elapsed() * 19);
+    tokio::time::sleep(delay).await;
+  }
+}
+
+async fn tick() -> Result<(), VoyagerError> {
+  event!(Level::DEBUG, "Reaping expired deployments.");
+
+  let deployments = repositories::deployments::find_expired(chrono::Utc::now().timestamp()).await?;
+  for deployment in deployments {
+    if matches!(
+      deployment.state,
+      DeploymentState::Queued | DeploymentState::Cloning | DeploymentState::Building | DeploymentState::Starting
+    ) {
+      continue;
+    }
+
+    let id = deployment.id.to_hex();
+    event!(Level::INFO, "{} deployment {id} at {} has expired. Deleting it.", deployment.mode, deployment.host);
+
+    match deployments::delete(id.clone()).await {
+      Ok(()) => event!(Level::INFO, "Expired deployment {id} was deleted."),
+      Err(e) => event!(Level::WARN, "Failed to delete expired deployment {id}: {}", e.message),
+    }
+  }
+
+  event!(Level::DEBUG, "Done reaping expired deployments.");
+
+  Ok(())
+}
@@ -0,0 +1,88 @@
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::modules::docker;
+use crate::types::model::deployment::{Deployment, DeploymentState};
+use crate::types::other::voyager_error::VoyagerError;
+
+const INTERRUPTED_REASON: &str = "Interrupted by a restart of Voyager";
+
+// Deployments being deployed or deleted when Voyager stopped would be stuck in that state, refusing to be
+// redeployed, rolled back or deleted. A half done redeploy or rollback is undone, and deployments that still
+// have their container go back to Deployed or Stopped. The others are failed, so that they can be redeployed
+// or deleted again
+pub async fn recover_interrupted() {
+  let deployments = match repositories::deployments::retrieve_all(None, None).await {
+    Ok(deployments) => deployments,
+    Err(e) => {
+      event!(Level::WARN, "Failed to recover interrupted deployments: {}", e.message);
+      return;
+    },
+  };
+
+  for deployment in deployments {
+    if !matches!(
+      deployment.state,
+      DeploymentState::Queued
+        | DeploymentState::Cloning
+        | DeploymentState::Building
+        | DeploymentState::Starting
+        | DeploymentState::Deleting
+    ) {
+      continue;
+    }
+
+    let id = deployment.id.to_hex();
+    let state = match recover(&deployment).await {
+      Ok(state) => state,
+      Err(e) => {
+        event!(Level::WARN, "Failed to recover the container of deployment {id}: {}", e.message);
+        DeploymentState::Failed
+      },
+    };
+
+    event!(Level::WARN, "Deployment {id} was interrupted while {}, it is now {state}.", deployment.state);
+    let _ = repositories::deployments::update_state(&id, state, Some(INTERRUPTED_REASON.to_string())).await;
+  }
+}
+
+async fn recover(deployment: &Deployment) -> Result<DeploymentState, VoyagerError> {
+  // A deletion may have removed part of its resources already, so it is only ever tried again
+  if matches!(deployment.state, DeploymentState::Deleting) {
+    return Ok(DeploymentState::Failed);
+  }
+
+  // The container a redeploy or rollback was replacing takes its place back, as the record still describes it
+  let name = deployment.container_name.clone();
+  let retired_name = format!("{name}-retired");
+  if docker::container_exists(retired_name.clone()).await? {
+    if docker::container_exists(name.clone()).await? {
+      if docker::is_container_running(name.clone()).await? {
+        docker::stop_container(name.clone()).await?;
+      }
+      docker::delete_container(name.clone()).await?;
+    }
+    docker::rename_container(retired_name, name.clone()).await?;
+    docker::start_container(name.clone()).await?;
+  }
+
+  // A first deploy never got to save its container, which would take the name of the next attempt
+  if deployment.container_id.is_none() {
+    if docker::container_exists(name.clone()).await? {
+      if docker::is_container_running(name.clone()).await? {
+        docker::stop_container(name.clone()).await?;
+      }
+      docker::delete_container(name).await?;
+    }
+    return Ok(DeploymentState::Failed);
+  }
+  if !docker::container_exists(name.clone()).await? {
+    return Ok(DeploymentState::Failed);
+  }
+
+  Ok(if docker::is_container_running(name).await? {
+    DeploymentState::Deployed
+  } else {
+    DeploymentState::Stopped
+  })
+}
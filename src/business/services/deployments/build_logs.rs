@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use axum::http::StatusCode;
+use lazy_static::lazy_static;
+use tokio::sync::broadcast;
+use tracing::{event, Level};
+
+use crate::types::other::{log_stream::LogStream, voyager_error::VoyagerError};
+
+lazy_static! {
+  // Output of the deployments currently being deployed, by deployment id
+  static ref BUILD_LOGS: Mutex<HashMap<String, LogStream>> = Mutex::new(HashMap::new());
+}
+
+pub(super) fn open_build_logs(id: &str) -> LogStream {
+  let log_stream = LogStream::new();
+  BUILD_LOGS
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+    .insert(id.to_string(), log_stream.clone());
+
+  log_stream
+}
+
+// Followers are disconnected once every other handle to the stream is dropped
+pub(super) fn close_build_logs(id: &str) {
+  BUILD_LOGS
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+    .remove(id);
+}
+
+pub fn follow_build_logs(id: &str) -> Result<(Vec<String>, broadcast::Receiver<String>), VoyagerError> {
+  event!(Level::INFO, "Following build logs. Id: {id}");
+
+  let log_stream = BUILD_LOGS
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+    .get(id)
+    .cloned()
+    .ok_or_else(|| VoyagerError::no_build_logs(id))?;
+
+  Ok(log_stream.subscribe())
+}
+
+impl VoyagerError {
+  fn no_build_logs(id: &str) -> Self {
+    Self::new(
+      format!("Deployment '{id}' is not being deployed"),
+      StatusCode::NOT_FOUND,
+      true,
+      None,
+    )
+  }
+}
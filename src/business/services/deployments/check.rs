@@ -3,7 +3,7 @@ use axum::http::StatusCode;
 
 use crate::business::repositories;
 use crate::business::services::SERVICES_RUNTIME;
-use crate::types::model::deployment::Mode;
+use crate::types::model::deployment::{DeploymentState, Mode};
 use crate::types::other::voyager_error::VoyagerError;
 use crate::utils::runtime_helpers::RuntimeSpawnHandled;
 
@@ -28,6 +28,19 @@ pub async fn check(
   let future = async move {
     let result = repositories::deployments::find_by_name(&host).await?;
     match result {
+      // A failed deployment that holds no resources anymore just makes way for the new one. One whose
+      // deletion failed or that a restart interrupted may still hold some, and has to be deleted first
+      Some(deployment)
+        if matches!(deployment.state, DeploymentState::Failed)
+          && deployment.container_id.is_none()
+          && deployment.dns_record_id.is_none()
+          && deployment.image_id.is_none() =>
+      {
+        let id = deployment.id.to_hex();
+        repositories::deployments::delete(&id).await?;
+        repositories::builds::delete_by_deployment(&id).await?;
+        repositories::environment_variables::delete_by_deployment(&id).await
+      },
       Some(_) => Err(VoyagerError::new(
         format!("Deployment at this subdomain already exists!"),
         StatusCode::BAD_REQUEST,
@@ -40,7 +53,7 @@ pub async fn check(
 
     if let Mode::Production = mode {
       let result = repositories::deployments::find_by_repo_branch(&repo_url, &final_branch).await?;
-      match result {
+      match result.iter().find(|d| !matches!(d.state, DeploymentState::Failed)) {
         Some(_) => Err(VoyagerError::new(
           format!("A Production deployment for this repository and branch already exists!"),
           StatusCode::BAD_REQUEST,
@@ -1,12 +1,20 @@
+use axum::http::StatusCode;
 use tracing::{event, Level};
 
 use crate::{
   business::{repositories, services::SERVICES_RUNTIME},
   modules::{
-    cloudflare::delete_dns_record,
+    dns::DNS_PROVIDER,
     docker::{self, delete_container, delete_image, is_container_running},
+    notifications,
+  },
+  types::{
+    model::deployment::DeploymentState,
+    other::{
+      notification::{Notification, NotificationEvent},
+      voyager_error::VoyagerError,
+    },
   },
-  types::other::voyager_error::VoyagerError,
   utils::runtime_helpers::RuntimeSpawnHandled,
 };
 
@@ -14,23 +22,74 @@ pub async fn delete(deployment_id: String) -> Result<(), VoyagerError> {
   event!(Level::INFO, "Deleting deployment: {}", &deployment_id);
 
   let future = async move {
-    let deployment = repositories::deployments::find_by_id(&deployment_id).await?;
-    let name = deployment.container_name;
+    // Claimed in a single update, so that no redeploy, rollback or other delete can start meanwhile
+    let from = [DeploymentState::Deployed, DeploymentState::Stopped, DeploymentState::Failed];
+    let Some(deployment) = repositories::deployments::transition_state(&deployment_id, &from, DeploymentState::Deleting).await? else {
+      let current = repositories::deployments::find_by_id(&deployment_id).await?;
+      return Err(VoyagerError::delete_state(&deployment_id, current.state));
+    };
 
-    if is_container_running(name.clone()).await? {
-      docker::stop_container(name.clone()).await?;
-    }
+    // Each resource is forgotten as soon as it is gone, so that deleting again after a failure
+    // picks up where this attempt stopped
+    let mut deployment = deployment;
+    deployment.state = DeploymentState::Deleting;
+    let result = async {
+      let name = deployment.container_name.clone();
 
-    delete_dns_record(&deployment.dns_record_id).await?;
-    
-    delete_container(name.clone()).await?;
-    delete_image(deployment.image_id).await?;
+      // Failed deployments have already had their resources rolled back
+      if deployment.container_id.is_some() {
+        if is_container_running(name.clone()).await? {
+          docker::stop_container(name.clone()).await?;
+        }
+        delete_container(name.clone()).await?;
+        deployment.container_id = None;
+        deployment.updated_at = chrono::Utc::now().timestamp();
+        repositories::deployments::update_release(&deployment).await?;
+      }
 
-    repositories::deployments::delete(&deployment_id).await?;
+      if let Some(dns_record_id) = &deployment.dns_record_id {
+        DNS_PROVIDER.delete_record(dns_record_id).await?;
+        deployment.dns_record_id = None;
+        deployment.updated_at = chrono::Utc::now().timestamp();
+        repositories::deployments::update_release(&deployment).await?;
+      }
 
-    // TODO: notify user via email
+      if let Some(image_id) = &deployment.image_id {
+        delete_image(image_id.clone()).await?;
+        deployment.image_id = None;
+        deployment.updated_at = chrono::Utc::now().timestamp();
+        repositories::deployments::update_release(&deployment).await?;
+      }
+      // Along with the images kept to roll back to, which are of no use anymore. The current one is among
+      // them, but already gone
+      let builds = repositories::builds::find_by_deployment(&deployment_id).await?;
+      let mut retained_image_ids = builds.into_iter().filter_map(|b| b.image_id).collect::<Vec<_>>();
+      retained_image_ids.sort();
+      retained_image_ids.dedup();
+      for image_id in retained_image_ids {
+        let _ = delete_image(image_id).await;
+      }
+
+      repositories::deployments::delete(&deployment_id).await?;
+      repositories::builds::delete_by_deployment(&deployment_id).await?;
+      repositories::environment_variables::delete_by_deployment(&deployment_id).await
+    }
+    .await;
 
-    Ok(())
+    match &result {
+      Ok(()) => notifications::notify(Notification::new(NotificationEvent::Deleted, &deployment, None)).await,
+      // Failed, so that deleting it can be tried again
+      Err(e) => {
+        let _ = repositories::deployments::update_state(
+          &deployment_id,
+          DeploymentState::Failed,
+          Some(e.message.clone()),
+        )
+        .await;
+      },
+    }
+
+    result
   };
 
   let result = SERVICES_RUNTIME
@@ -40,4 +99,15 @@ pub async fn delete(deployment_id: String) -> Result<(), VoyagerError> {
   event!(Level::DEBUG, "Done deleting deployment.");
 
   result
-}
\ No newline at end of file
+}
+
+impl VoyagerError {
+  fn delete_state(id: &str, state: DeploymentState) -> Self {
+    Self::new(
+      format!("Deployment '{id}' cannot be deleted while {state}"),
+      StatusCode::CONFLICT,
+      true,
+      None,
+    )
+  }
+}
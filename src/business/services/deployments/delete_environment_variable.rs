@@ -0,0 +1,23 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+// Takes effect the next time the deployment is redeployed
+pub async fn delete_environment_variable(id: String, name: String) -> Result<(), VoyagerError> {
+  event!(Level::INFO, "Deleting environment variable {name} of deployment. Id: {id}");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::delete_environment_variable", async move {
+      repositories::deployments::find_by_id(&id).await?;
+      repositories::environment_variables::delete(&id, &name).await
+    })
+    .await?;
+
+  event!(Level::DEBUG, "Done deleting environment variable.");
+
+  result
+}
@@ -0,0 +1,13 @@
+use crate::{business::repositories, types::other::voyager_error::VoyagerError, utils};
+
+// The environment of the deployment's container, as NAME=value pairs with its secrets decrypted
+pub(super) async fn container_environment(id: &str) -> Result<Vec<String>, VoyagerError> {
+  repositories::environment_variables::find_by_deployment(id)
+    .await?
+    .into_iter()
+    .map(|variable| {
+      let value = if variable.secret { utils::decrypt_secret(&variable.value)? } else { variable.value };
+      Ok(format!("{}={value}", variable.name))
+    })
+    .collect()
+}
@@ -0,0 +1,32 @@
+use axum::http::StatusCode;
+
+use crate::{
+  configs::environment::{PREVIEW_TTL, PRODUCTION_TTL},
+  types::{model::deployment::Mode, other::voyager_error::VoyagerError},
+};
+
+// When a deployment given a TTL in seconds expires. Without one, the default of its mode is used,
+// and a TTL of 0 means it never expires.
+pub(super) fn resolve_expires_at(ttl: Option<i64>, mode: Mode, now: i64) -> Result<Option<i64>, VoyagerError> {
+  let default_ttl = match mode {
+    Mode::Preview => *PREVIEW_TTL,
+    Mode::Production => *PRODUCTION_TTL,
+  };
+
+  match ttl.or(default_ttl) {
+    Some(ttl) if ttl < 0 => Err(VoyagerError::negative_ttl()),
+    Some(0) | None => Ok(None),
+    Some(ttl) => Ok(Some(now.saturating_add(ttl))),
+  }
+}
+
+impl VoyagerError {
+  fn negative_ttl() -> Self {
+    Self::new(
+      "TTL cannot be negative".to_string(),
+      StatusCode::BAD_REQUEST,
+      true,
+      None,
+    )
+  }
+}
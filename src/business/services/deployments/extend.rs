@@ -0,0 +1,46 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::business::services::SERVICES_RUNTIME;
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::voyager_error::VoyagerError;
+use crate::utils::runtime_helpers::RuntimeSpawnHandled;
+
+use super::expiry::resolve_expires_at;
+
+// Makes the deployment expire `ttl` seconds from now, returning when it now expires
+pub async fn extend(id: String, ttl: Option<i64>) -> Result<Option<i64>, VoyagerError> {
+  event!(Level::INFO, "Extending deployment. Id: {id}, TTL: {ttl:?}");
+
+  let future = async move {
+    let deployment = repositories::deployments::find_by_id(&id).await?;
+    if matches!(deployment.state, DeploymentState::Deleting) {
+      return Err(VoyagerError::extend_state(&id, deployment.state));
+    }
+
+    let expires_at = resolve_expires_at(ttl, deployment.mode, chrono::Utc::now().timestamp())?;
+    repositories::deployments::update_expires_at(&id, expires_at).await?;
+
+    Ok(expires_at)
+  };
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::extend", future)
+    .await?;
+
+  event!(Level::DEBUG, "Done extending deployment.");
+
+  result
+}
+
+impl VoyagerError {
+  fn extend_state(id: &str, state: DeploymentState) -> Self {
+    Self::new(
+      format!("Deployment '{id}' cannot be extended while {state}"),
+      StatusCode::CONFLICT,
+      true,
+      None,
+    )
+  }
+}
@@ -0,0 +1,21 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{model::build::Build, other::voyager_error::VoyagerError},
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+pub async fn get_build(id: String, build_id: String) -> Result<Build, VoyagerError> {
+  event!(Level::INFO, "Retrieving build. Deployment id: {id}, build id: {build_id}");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::get_build", async move {
+      repositories::builds::find_by_id(&id, &build_id).await
+    })
+    .await?;
+
+  event!(Level::DEBUG, "Done retrieving build.");
+
+  result
+}
@@ -1,18 +1,22 @@
+use axum::http::StatusCode;
+use tokio::sync::mpsc;
 use tracing::{event, Level};
 
 use crate::business::repositories;
 use crate::business::services::SERVICES_RUNTIME;
 use crate::modules::docker;
+use crate::types::model::deployment::Deployment;
+use crate::types::other::container_logs::{ContainerLog, ContainerLogsOptions};
 use crate::types::other::voyager_error::VoyagerError;
 use crate::utils::runtime_helpers::RuntimeSpawnHandled;
 
-pub async fn get_logs(id: String) -> Result<Vec<String>, VoyagerError> {
+pub async fn get_logs(id: String, options: ContainerLogsOptions) -> Result<Vec<ContainerLog>, VoyagerError> {
   event!(Level::INFO, "Retrieving deployment logs. Id: {id}");
 
   let future = async move {
-    let deployment = repositories::deployments::find_by_id(&id).await?;
+    let deployment = find_with_container(&id).await?;
 
-    docker::get_logs(&deployment.container_name).await
+    docker::get_logs(&deployment.container_name, options).await
   };
 
   let result = SERVICES_RUNTIME
@@ -23,3 +27,38 @@ pub async fn get_logs(id: String) -> Result<Vec<String>, VoyagerError> {
 
   result
 }
+
+pub async fn follow_logs(id: String, options: ContainerLogsOptions) -> Result<mpsc::Receiver<ContainerLog>, VoyagerError> {
+  event!(Level::INFO, "Following deployment logs. Id: {id}");
+
+  let future = async move {
+    let deployment = find_with_container(&id).await?;
+
+    Ok(docker::follow_logs(deployment.container_name, options))
+  };
+
+  SERVICES_RUNTIME
+    .spawn_handled("services::deployments::follow_logs", future)
+    .await?
+}
+
+// Failed deployments had their container rolled back, so there are no logs to read
+async fn find_with_container(id: &str) -> Result<Deployment, VoyagerError> {
+  let deployment = repositories::deployments::find_by_id(id).await?;
+  if deployment.container_id.is_none() {
+    return Err(VoyagerError::no_container(id));
+  }
+
+  Ok(deployment)
+}
+
+impl VoyagerError {
+  fn no_container(id: &str) -> Self {
+    Self::new(
+      format!("Deployment '{id}' has no container"),
+      StatusCode::NOT_FOUND,
+      true,
+      None,
+    )
+  }
+}
@@ -0,0 +1,50 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::repositories,
+  configs::environment::IMAGE_RETENTION,
+  modules::docker,
+  types::other::voyager_error::VoyagerError,
+};
+
+// Keeps the images of the IMAGE_RETENTION latest builds, and the one the deployment runs, to roll back to.
+// Older images are deleted and forgotten by their builds. Failing to do so is only logged
+pub(super) async fn prune_images(deployment_id: &str) {
+  if let Err(e) = try_prune_images(deployment_id).await {
+    event!(Level::WARN, "Failed to prune images of deployment {deployment_id}: {}", e.message);
+  }
+}
+
+async fn try_prune_images(deployment_id: &str) -> Result<(), VoyagerError> {
+  let deployment = repositories::deployments::find_by_id(deployment_id).await?;
+  let builds = repositories::builds::find_by_deployment(deployment_id).await?;
+
+  // Builds are newest first, and a rebuild without changes or a rollback reuses the image of an older build
+  let mut images: Vec<String> = Vec::new();
+  for image_id in builds.into_iter().filter_map(|b| b.image_id) {
+    if !images.contains(&image_id) {
+      images.push(image_id);
+    }
+  }
+  let expired = images
+    .into_iter()
+    .skip(*IMAGE_RETENTION)
+    .filter(|image_id| deployment.image_id.as_ref() != Some(image_id));
+
+  for image_id in expired {
+    event!(Level::INFO, "Image {image_id} of deployment {deployment_id} is past retention.");
+    // Images that are already gone are forgotten all the same
+    let _ = docker::delete_image(image_id.clone()).await;
+    repositories::builds::clear_image(deployment_id, &image_id).await?;
+  }
+
+  Ok(())
+}
+
+// Whether a build of the deployment still keeps the image, i.e. it must not be deleted
+pub(super) async fn is_image_retained(deployment_id: &str, image_id: &str) -> bool {
+  repositories::builds::find_by_deployment(deployment_id)
+    .await
+    // Assume it is when unsure, at worst an unused image is left behind
+    .map_or(true, |builds| builds.iter().any(|b| b.image_id.as_deref() == Some(image_id)))
+}
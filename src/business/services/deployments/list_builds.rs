@@ -0,0 +1,22 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{model::build::Build, other::voyager_error::VoyagerError},
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+pub async fn list_builds(id: String) -> Result<Vec<Build>, VoyagerError> {
+  event!(Level::INFO, "Retrieving builds of deployment. Id: {id}");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::list_builds", async move {
+      repositories::deployments::find_by_id(&id).await?;
+      repositories::builds::find_by_deployment(&id).await
+    })
+    .await?;
+
+  event!(Level::DEBUG, "Done retrieving builds.");
+
+  result
+}
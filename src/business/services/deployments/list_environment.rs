@@ -0,0 +1,22 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{model::environment_variable::EnvironmentVariable, other::voyager_error::VoyagerError},
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+pub async fn list_environment(id: String) -> Result<Vec<EnvironmentVariable>, VoyagerError> {
+  event!(Level::INFO, "Retrieving environment variables of deployment. Id: {id}");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::list_environment", async move {
+      repositories::deployments::find_by_id(&id).await?;
+      repositories::environment_variables::find_by_deployment(&id).await
+    })
+    .await?;
+
+  event!(Level::DEBUG, "Done retrieving environment variables.");
+
+  result
+}
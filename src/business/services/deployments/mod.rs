@@ -4,6 +4,25 @@ mod list;
 mod get_logs;
 mod get;
 mod check;
+mod redeploy;
+mod rollback;
+mod stop;
+mod start;
+mod restart;
+mod extend;
+mod build_logs;
+mod list_builds;
+mod get_build;
+mod list_environment;
+mod set_environment_variable;
+mod delete_environment_variable;
+mod set_build_options;
+mod set_resource_limits;
+mod environment;
+mod resources;
+mod images;
+mod expiry;
+mod transaction;
 
 pub use delete::*;
 pub use new::*;
@@ -11,3 +30,17 @@ pub use list::*;
 pub use get_logs::*;
 pub use get::*;
 pub use check::*;
+pub use redeploy::*;
+pub use rollback::*;
+pub use stop::*;
+pub use start::*;
+pub use restart::*;
+pub use extend::*;
+pub use build_logs::*;
+pub use list_builds::*;
+pub use get_build::*;
+pub use list_environment::*;
+pub use set_environment_variable::*;
+pub use delete_environment_variable::*;
+pub use set_build_options::*;
+pub use set_resource_limits::*;
@@ -0,0 +1,79 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::business::services::SERVICES_RUNTIME;
+use crate::modules::{docker, notifications};
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::notification::{Notification, NotificationEvent};
+use crate::types::other::voyager_error::VoyagerError;
+use crate::utils::runtime_helpers::RuntimeSpawnHandled;
+
+use super::build_logs::open_build_logs;
+use super::images::prune_images;
+use super::transaction::{GitClone, TransactionManager};
+
+pub async fn redeploy(id: String) -> Result<String, VoyagerError> {
+  event!(Level::INFO, "Redeploying deployment. Id: {id}");
+
+  let future = async move {
+    // Queued right away, so that a concurrent redeploy or rollback of the same deployment is refused
+    let from = [DeploymentState::Deployed, DeploymentState::Stopped, DeploymentState::Failed];
+    let Some(previous) = repositories::deployments::transition_state(&id, &from, DeploymentState::Queued).await? else {
+      let current = repositories::deployments::find_by_id(&id).await?;
+      return Err(VoyagerError::redeploy_state(&id, current.state));
+    };
+
+    // "default" is stored when no branch was given, so the remote's default branch is cloned again
+    let branch = Some(previous.branch.clone()).filter(|b| b != "default");
+
+    let mut manager = TransactionManager {
+      next: Some(Box::new(GitClone)),
+
+      repo_url: Some(previous.repo_url.clone()),
+      branch,
+      final_branch: Some(previous.branch.clone()),
+      host: Some(previous.host.clone()),
+      mode: Some(previous.mode),
+      container_name: Some(previous.container_name.clone()),
+      state: Some(DeploymentState::Queued),
+      log_stream: open_build_logs(&id),
+      deployment: Some(previous.clone()),
+      // Deployments that failed before having a container are deployed as if for the first time
+      previous: Some(previous.clone()).filter(|p| p.container_id.is_some()),
+      ..Default::default()
+    };
+    if let Err(e) = manager.start().await {
+      notifications::notify(Notification::new(NotificationEvent::Failed, &previous, Some(e.message.clone()))).await;
+      return Err(e);
+    }
+    notifications::notify(Notification::new(NotificationEvent::Redeployed, &previous, None)).await;
+
+    // The new container is already serving, so failing to clean up the old one is not fatal
+    if let Some(retired_container_name) = manager.retired_container_name {
+      let _ = docker::delete_container(retired_container_name).await;
+    }
+    prune_images(&id).await;
+
+    manager.final_id.ok_or_else(VoyagerError::null_db_id)
+  };
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::redeploy", future)
+    .await?;
+
+  event!(Level::DEBUG, "Done redeploying deployment.");
+
+  result
+}
+
+impl VoyagerError {
+  fn redeploy_state(id: &str, state: DeploymentState) -> Self {
+    Self::new(
+      format!("Deployment '{id}' cannot be redeployed while {state}"),
+      StatusCode::CONFLICT,
+      true,
+      None,
+    )
+  }
+}
@@ -0,0 +1,32 @@
+use crate::{
+  configs::environment::{
+    PREVIEW_CPUS, PREVIEW_MEMORY_MB, PREVIEW_MEMORY_SWAP_MB, PREVIEW_PIDS, PRODUCTION_CPUS,
+    PRODUCTION_MEMORY_MB, PRODUCTION_MEMORY_SWAP_MB, PRODUCTION_PIDS,
+  },
+  types::model::deployment::{Mode, ResourceLimits},
+};
+
+// The limits applied to the deployment's container, falling back to the defaults of its mode
+pub(super) fn effective_resource_limits(limits: &ResourceLimits, mode: Mode) -> ResourceLimits {
+  let defaults = match mode {
+    Mode::Preview => ResourceLimits {
+      cpus: *PREVIEW_CPUS,
+      memory_mb: *PREVIEW_MEMORY_MB,
+      memory_swap_mb: *PREVIEW_MEMORY_SWAP_MB,
+      pids: *PREVIEW_PIDS,
+    },
+    Mode::Production => ResourceLimits {
+      cpus: *PRODUCTION_CPUS,
+      memory_mb: *PRODUCTION_MEMORY_MB,
+      memory_swap_mb: *PRODUCTION_MEMORY_SWAP_MB,
+      pids: *PRODUCTION_PIDS,
+    },
+  };
+
+  ResourceLimits {
+    cpus: limits.cpus.or(defaults.cpus),
+    memory_mb: limits.memory_mb.or(defaults.memory_mb),
+    memory_swap_mb: limits.memory_swap_mb.or(defaults.memory_swap_mb),
+    pids: limits.pids.or(defaults.pids),
+  }
+}
@@ -0,0 +1,106 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::business::services::SERVICES_RUNTIME;
+use crate::modules::{docker, notifications};
+use crate::types::model::build::BuildOutcome;
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::notification::{Notification, NotificationEvent};
+use crate::types::other::voyager_error::VoyagerError;
+use crate::utils::runtime_helpers::RuntimeSpawnHandled;
+
+use super::build_logs::open_build_logs;
+use super::images::prune_images;
+use super::transaction::{RetireContainer, TransactionManager};
+
+// Replaces the container of a deployment with one of the image of an older build, without rebuilding it
+pub async fn rollback(id: String, build_id: String) -> Result<String, VoyagerError> {
+  event!(Level::INFO, "Rolling back deployment. Id: {id}, build id: {build_id}");
+
+  let future = async move {
+    let build = repositories::builds::find_by_id(&id, &build_id).await?;
+    let (BuildOutcome::Succeeded, Some(image_id)) = (build.outcome, build.image_id) else {
+      return Err(VoyagerError::rollback_no_image(&build_id));
+    };
+
+    // Queued right away, so that a concurrent redeploy or rollback of the same deployment is refused
+    let from = [DeploymentState::Deployed, DeploymentState::Stopped];
+    let Some(previous) = repositories::deployments::transition_state(&id, &from, DeploymentState::Queued).await? else {
+      let current = repositories::deployments::find_by_id(&id).await?;
+      return Err(VoyagerError::rollback_state(&id, current.state));
+    };
+    if previous.image_id.as_ref() == Some(&image_id) {
+      let _ = repositories::deployments::update_state(&id, previous.state, previous.failure_reason.clone()).await;
+      return Err(VoyagerError::rollback_same_image(&id, &build_id));
+    }
+
+    let mut manager = TransactionManager {
+      next: Some(Box::new(RetireContainer)),
+
+      host: Some(previous.host.clone()),
+      mode: Some(previous.mode),
+      container_name: Some(previous.container_name.clone()),
+      image_id: Some(image_id),
+      commit: build.commit,
+      state: Some(DeploymentState::Queued),
+      log_stream: open_build_logs(&id),
+      deployment: Some(previous.clone()),
+      previous: Some(previous.clone()),
+      rolled_back_from: Some(build.id),
+      ..Default::default()
+    };
+    manager.log_stream.push(format!("Rolling back to the image of build {build_id}."));
+    if let Err(e) = manager.start().await {
+      notifications::notify(Notification::new(NotificationEvent::Failed, &previous, Some(e.message.clone()))).await;
+      return Err(e);
+    }
+    let reason = format!("Rolled back to build {build_id}.");
+    notifications::notify(Notification::new(NotificationEvent::Redeployed, &previous, Some(reason))).await;
+
+    // The new container is already serving, so failing to clean up the old one is not fatal
+    if let Some(retired_container_name) = manager.retired_container_name {
+      let _ = docker::delete_container(retired_container_name).await;
+    }
+    prune_images(&id).await;
+
+    manager.final_id.ok_or_else(VoyagerError::null_db_id)
+  };
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::rollback", future)
+    .await?;
+
+  event!(Level::DEBUG, "Done rolling back deployment.");
+
+  result
+}
+
+impl VoyagerError {
+  fn rollback_state(id: &str, state: DeploymentState) -> Self {
+    Self::new(
+      format!("Deployment '{id}' cannot be rolled back while {state}"),
+      StatusCode::CONFLICT,
+      true,
+      None,
+    )
+  }
+
+  fn rollback_no_image(build_id: &str) -> Self {
+    Self::new(
+      format!("Build '{build_id}' has no image to roll back to, as it failed or is past retention"),
+      StatusCode::BAD_REQUEST,
+      true,
+      None,
+    )
+  }
+
+  fn rollback_same_image(id: &str, build_id: &str) -> Self {
+    Self::new(
+      format!("Deployment '{id}' already runs the image of build '{build_id}'"),
+      StatusCode::CONFLICT,
+      true,
+      None,
+    )
+  }
+}
@@ -0,0 +1,59 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{model::deployment::BuildOptions, other::voyager_error::VoyagerError},
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+use super::set_environment_variable::is_valid_variable_name;
+
+// Takes effect the next time the deployment is redeployed
+pub async fn set_build_options(id: String, build_options: BuildOptions) -> Result<(), VoyagerError> {
+  event!(Level::INFO, "Setting build options of deployment. Id: {id}");
+
+  validate_build_options(&build_options)?;
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::set_build_options", async move {
+      repositories::deployments::update_build_options(&id, &build_options).await
+    })
+    .await?;
+
+  event!(Level::DEBUG, "Done setting build options.");
+
+  result
+}
+
+pub fn validate_build_options(build_options: &BuildOptions) -> Result<(), VoyagerError> {
+  if let Some(name) = build_options.build_args.keys().find(|name| !is_valid_variable_name(name)) {
+    return Err(VoyagerError::build_arg_name(name));
+  }
+
+  if build_options.target.as_ref().is_some_and(|t| t.is_empty() || t.contains(char::is_whitespace)) {
+    return Err(VoyagerError::build_target_name());
+  }
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn build_arg_name(name: &str) -> Self {
+    Self::new(
+      format!("Invalid build argument name '{name}'. Names can only have alphanumerics and underscores, and cannot start with a digit."),
+      StatusCode::BAD_REQUEST,
+      true,
+      None,
+    )
+  }
+
+  fn build_target_name() -> Self {
+    Self::new(
+      "Build target cannot be empty nor have whitespaces".to_string(),
+      StatusCode::BAD_REQUEST,
+      true,
+      None,
+    )
+  }
+}
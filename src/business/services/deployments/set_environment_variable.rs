@@ -0,0 +1,77 @@
+use axum::http::StatusCode;
+use lazy_static::lazy_static;
+use regex::Regex;
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::other::{new_deployment::NewEnvironmentVariable, voyager_error::VoyagerError},
+  utils::{self, runtime_helpers::RuntimeSpawnHandled, ExpectError},
+};
+
+lazy_static! {
+  static ref NAME_REGEX: Regex = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$")
+    .expect_error(|e| format!("Failed to compile environment variable name regex: {e}"));
+}
+
+// Takes effect the next time the deployment is redeployed
+pub async fn set_environment_variable(
+  id: String,
+  name: String,
+  value: String,
+  secret: bool,
+) -> Result<(), VoyagerError> {
+  event!(Level::INFO, "Setting environment variable {name} of deployment. Id: {id}");
+
+  validate_environment_variable_name(&name)?;
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::set_environment_variable", async move {
+      repositories::deployments::find_by_id(&id).await?;
+
+      let value = if secret { utils::encrypt_secret(&value)? } else { value };
+      repositories::environment_variables::upsert(&id, &name, value, secret).await
+    })
+    .await?;
+
+  event!(Level::DEBUG, "Done setting environment variable.");
+
+  result
+}
+
+pub fn validate_environment_variable_name(name: &str) -> Result<(), VoyagerError> {
+  if !is_valid_variable_name(name) {
+    return Err(VoyagerError::environment_variable_name(name));
+  }
+
+  Ok(())
+}
+
+// Also used for build arguments
+pub(super) fn is_valid_variable_name(name: &str) -> bool {
+  NAME_REGEX.is_match(name)
+}
+
+// Variables as they are stored: name, value (encrypted for secrets) and whether it is a secret
+pub(super) fn encrypt_environment(
+  environment: Vec<NewEnvironmentVariable>,
+) -> Result<Vec<(String, String, bool)>, VoyagerError> {
+  environment
+    .into_iter()
+    .map(|variable| {
+      let value = if variable.secret { utils::encrypt_secret(&variable.value)? } else { variable.value };
+      Ok((variable.name, value, variable.secret))
+    })
+    .collect()
+}
+
+impl VoyagerError {
+  fn environment_variable_name(name: &str) -> Self {
+    Self::new(
+      format!("Invalid environment variable name '{name}'. Names can only have alphanumerics and underscores, and cannot start with a digit."),
+      StatusCode::BAD_REQUEST,
+      true,
+      None,
+    )
+  }
+}
@@ -0,0 +1,91 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  modules::docker,
+  types::{
+    model::deployment::{DeploymentState, ResourceLimits},
+    other::voyager_error::VoyagerError,
+  },
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+use super::resources::effective_resource_limits;
+
+// Applied right away to the running container, and on every redeploy after that
+pub async fn set_resource_limits(id: String, resource_limits: ResourceLimits) -> Result<(), VoyagerError> {
+  event!(Level::INFO, "Setting resource limits of deployment. Id: {id}");
+
+  validate_resource_limits(&resource_limits)?;
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::set_resource_limits", async move {
+      let deployment = repositories::deployments::find_by_id(&id).await?;
+
+      // The container first, so that the stored limits always match it
+      let has_container = deployment.container_id.is_some()
+        && matches!(deployment.state, DeploymentState::Deployed | DeploymentState::Stopped);
+      if has_container {
+        let limits = effective_resource_limits(&resource_limits, deployment.mode);
+        docker::update_container_resources(deployment.container_name.clone(), limits).await?;
+      }
+
+      if let Err(e) = repositories::deployments::update_resource_limits(&id, &resource_limits).await {
+        if has_container {
+          let limits = effective_resource_limits(&deployment.resource_limits, deployment.mode);
+          let _ = docker::update_container_resources(deployment.container_name, limits).await;
+        }
+        return Err(e);
+      }
+
+      Ok(())
+    })
+    .await?;
+
+  event!(Level::DEBUG, "Done setting resource limits.");
+
+  result
+}
+
+pub fn validate_resource_limits(resource_limits: &ResourceLimits) -> Result<(), VoyagerError> {
+  if resource_limits.cpus.is_some_and(|cpus| !cpus.is_finite() || cpus <= 0.0) {
+    return Err(VoyagerError::resource_limits("cpus must be greater than 0"));
+  }
+
+  // Docker's own minimum
+  if resource_limits.memory_mb.is_some_and(|memory| memory < 6) {
+    return Err(VoyagerError::resource_limits("memoryMb must be at least 6"));
+  }
+
+  // Docker takes them in bytes
+  let too_large = |mb: i64| mb.checked_mul(1024 * 1024).is_none();
+  if resource_limits.memory_mb.is_some_and(too_large) || resource_limits.memory_swap_mb.is_some_and(too_large) {
+    return Err(VoyagerError::resource_limits("memoryMb and memorySwapMb are too large"));
+  }
+
+  if let Some(memory_swap) = resource_limits.memory_swap_mb.filter(|m| *m != -1) {
+    if resource_limits.memory_mb.is_some_and(|memory| memory_swap < memory) || memory_swap < 6 {
+      return Err(VoyagerError::resource_limits(
+        "memorySwapMb must be -1, or at least as much as memoryMb",
+      ));
+    }
+  }
+
+  if resource_limits.pids.is_some_and(|pids| pids == 0 || pids < -1) {
+    return Err(VoyagerError::resource_limits("pids must be -1, or greater than 0"));
+  }
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn resource_limits(message: &str) -> Self {
+    Self::new(
+      format!("Invalid resource limits: {message}"),
+      StatusCode::BAD_REQUEST,
+      true,
+      None,
+    )
+  }
+}
@@ -0,0 +1,55 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::business::services::SERVICES_RUNTIME;
+use crate::modules::docker;
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::voyager_error::VoyagerError;
+use crate::utils::runtime_helpers::RuntimeSpawnHandled;
+
+pub async fn start(id: String) -> Result<DeploymentState, VoyagerError> {
+  event!(Level::INFO, "Starting deployment. Id: {id}");
+
+  let future = async move {
+    // Claimed as Starting, so that no redeploy, rollback or delete can start until the container is up
+    let from = [DeploymentState::Deployed, DeploymentState::Stopped];
+    let Some(deployment) = repositories::deployments::transition_state(&id, &from, DeploymentState::Starting).await? else {
+      let current = repositories::deployments::find_by_id(&id).await?;
+      return Err(VoyagerError::start_state(&id, current.state));
+    };
+
+    let started = match docker::is_container_running(deployment.container_name.clone()).await {
+      Ok(true) => Ok(()),
+      Ok(false) => docker::start_container(deployment.container_name).await,
+      Err(e) => Err(e),
+    };
+    if let Err(e) = started {
+      let _ = repositories::deployments::update_state(&id, deployment.state, deployment.failure_reason).await;
+      return Err(e);
+    }
+
+    repositories::deployments::update_state(&id, DeploymentState::Deployed, None).await?;
+
+    Ok(DeploymentState::Deployed)
+  };
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::start", future)
+    .await?;
+
+  event!(Level::DEBUG, "Done starting deployment.");
+
+  result
+}
+
+impl VoyagerError {
+  fn start_state(id: &str, state: DeploymentState) -> Self {
+    Self::new(
+      format!("Deployment '{id}' cannot be started while {state}"),
+      StatusCode::CONFLICT,
+      true,
+      None,
+    )
+  }
+}
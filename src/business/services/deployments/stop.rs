@@ -0,0 +1,54 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::business::services::SERVICES_RUNTIME;
+use crate::modules::docker;
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::voyager_error::VoyagerError;
+use crate::utils::runtime_helpers::RuntimeSpawnHandled;
+
+// Stopped deployments keep their container, image and DNS record, and are left alone by the health monitor
+pub async fn stop(id: String) -> Result<DeploymentState, VoyagerError> {
+  event!(Level::INFO, "Stopping deployment. Id: {id}");
+
+  let future = async move {
+    // Stopped before the container is, so that the health monitor doesn't start it again in between
+    let from = [DeploymentState::Deployed, DeploymentState::Stopped];
+    let Some(deployment) = repositories::deployments::transition_state(&id, &from, DeploymentState::Stopped).await? else {
+      let current = repositories::deployments::find_by_id(&id).await?;
+      return Err(VoyagerError::stop_state(&id, current.state));
+    };
+
+    let stopped = match docker::is_container_running(deployment.container_name.clone()).await {
+      Ok(true) => docker::stop_container(deployment.container_name).await,
+      Ok(false) => Ok(()),
+      Err(e) => Err(e),
+    };
+    if let Err(e) = stopped {
+      let _ = repositories::deployments::update_state(&id, deployment.state, deployment.failure_reason).await;
+      return Err(e);
+    }
+
+    Ok(DeploymentState::Stopped)
+  };
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::deployments::stop", future)
+    .await?;
+
+  event!(Level::DEBUG, "Done stopping deployment.");
+
+  result
+}
+
+impl VoyagerError {
+  fn stop_state(id: &str, state: DeploymentState) -> Self {
+    Self::new(
+      format!("Deployment '{id}' cannot be stopped while {state}"),
+      StatusCode::CONFLICT,
+      true,
+      None,
+    )
+  }
+}
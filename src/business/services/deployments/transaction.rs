@@ -0,0 +1,551 @@
+// The Struct TransactionManager is used as a 'stack' and will have all the arguments used by each Command.
+// They're also assigned in order, so there's no problem in using unwrap every time they're used.
+// It was done like this in order to avoid multiple arguments in each Command + multiple Clones, effectively reducing the amount of code and making it also more efficient.
+// Each case was thoroughly checked and should never panic. But any new changes should be carefully checked.
+#![allow(clippy::unwrap_used)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::business::{repositories, services::git_credentials};
+use crate::configs::environment::{DEPLOYMENTS_DIR, GIT_FETCH_DEPTH, HOST_IP, REPOSITORIES_DIR};
+use crate::modules::{dns::DNS_PROVIDER, git};
+use crate::types::model::build::{Build, BuildOutcome, BuildStep};
+use crate::types::other::{ignore_pattern::IgnorePattern, log_stream::LogStream, voyager_error::VoyagerError};
+use crate::utils::{self};
+use crate::modules::docker;
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use mongodb::bson::oid::ObjectId;
+use tracing::{event, Level};
+use uuid::Uuid;
+
+use super::build_logs::close_build_logs;
+use super::environment::container_environment;
+use super::images::is_image_retained;
+use super::resources::effective_resource_limits;
+
+use crate::{
+  modules::tar,
+  types::model::deployment::{Commit, Deployment, DeploymentState, Mode},
+  utils::Error,
+};
+
+// Keeps build records well under the 16MB MongoDB documents are limited to. The end of the output, where
+// errors are, is what is kept
+const MAX_BUILD_OUTPUT_BYTES: usize = 4 * 1024 * 1024;
+
+#[derive(Default)]
+pub(super) struct TransactionManager {
+  pub(super) history: Vec<Box<dyn Command>>,
+  pub(super) next: Option<Box<dyn Command>>,
+
+  pub(super) dir_as_path: Option<PathBuf>,
+  pub(super) ignore_patterns: Vec<IgnorePattern>,
+  pub(super) repo_url: Option<String>,
+  pub(super) branch: Option<String>,
+  pub(super) final_branch: Option<String>,
+  pub(super) container_id: Option<String>,
+  pub(super) host: Option<String>,
+  pub(super) mode: Option<Mode>,
+  // port: Option<u16>,
+  // internal_port: Option<u16>,
+  pub(super) container_name: Option<String>,
+  pub(super) image_id: Option<String>,
+  pub(super) dns_record_id: Option<String>,
+  pub(super) commit: Option<Commit>,
+
+  // The deployment as stored in the database, and the state it was last updated to
+  pub(super) deployment: Option<Deployment>,
+  pub(super) state: Option<DeploymentState>,
+
+  // Output of every step, streamed to whoever follows the deployment
+  pub(super) log_stream: LogStream,
+  // How long each step took, saved along with the output as the build record
+  pub(super) steps: Vec<BuildStep>,
+
+  // Only set when redeploying: the deployment being replaced and the name its container was moved to
+  pub(super) previous: Option<Deployment>,
+  pub(super) retired_container_name: Option<String>,
+  // Only set when rolling back: the build whose image is reused
+  pub(super) rolled_back_from: Option<ObjectId>,
+
+  pub(super) final_id: Option<String>,
+}
+
+impl TransactionManager {
+  pub(super) async fn start(&mut self) -> Result<(), VoyagerError> {
+    let id = self.deployment.as_ref().unwrap().id.to_hex();
+    let started_at = chrono::Utc::now().timestamp();
+
+    while let Some(mut command) = self.next.take() {
+      let step_start = Instant::now();
+      let result = match self.set_state(command.state()).await {
+        Ok(()) => command.execute(self).await,
+        Err(e) => Err(e),
+      };
+      self.steps.push(BuildStep {
+        name: command.name().to_string(),
+        duration_ms: i64::try_from(step_start.elapsed().as_millis()).unwrap_or(i64::MAX),
+        succeeded: result.is_ok(),
+      });
+
+      if let Err(e) = result {
+        self.log_stream.push(format!("Deployment failed: {}. Rolling back.", e.message));
+        self.undo().await;
+        self.fail(&e).await;
+        self.save_build(started_at, Some(&e)).await;
+        close_build_logs(&id);
+        return Err(e);
+      }
+
+      self.history.push(command);
+    }
+
+    self.log_stream.push("Deployment succeeded.");
+    self.save_build(started_at, None).await;
+    close_build_logs(&id);
+
+    Ok(())
+  }
+
+  async fn set_state(&mut self, state: DeploymentState) -> Result<(), VoyagerError> {
+    if self.state == Some(state) {
+      return Ok(());
+    }
+
+    let id = self.deployment.as_ref().unwrap().id.to_hex();
+    repositories::deployments::update_state(&id, state, None).await?;
+    self.state = Some(state);
+
+    Ok(())
+  }
+
+  // A failed redeploy rolls back to the previous deployment, so it keeps its state and only records the reason
+  async fn fail(&self, e: &VoyagerError) {
+    let id = self.deployment.as_ref().unwrap().id.to_hex();
+    let state = self.previous.as_ref().map_or(DeploymentState::Failed, |p| p.state);
+    let _ = repositories::deployments::update_state(&id, state, Some(e.message.clone())).await;
+  }
+
+  // Failing to record the build must not fail the deployment itself
+  async fn save_build(&self, started_at: i64, error: Option<&VoyagerError>) {
+    let build = Build {
+      id: ObjectId::new(),
+      deployment_id: self.deployment.as_ref().unwrap().id,
+      commit: self.commit.clone(),
+      image_id: if error.is_none() { self.image_id.clone() } else { None },
+      rolled_back_from: self.rolled_back_from,
+      outcome: if error.is_none() { BuildOutcome::Succeeded } else { BuildOutcome::Failed },
+      error: error.map(|e| e.message.clone()),
+      steps: self.steps.clone(),
+      output: self.log_stream.tail(MAX_BUILD_OUTPUT_BYTES),
+      started_at,
+      finished_at: chrono::Utc::now().timestamp(),
+    };
+
+    if let Err(e) = repositories::builds::save(build).await {
+      event!(Level::WARN, "Failed to save build record: {}", e.message);
+    }
+  }
+
+  async fn undo(&self) {
+    for command in self.history.iter().rev() {
+      command.undo(self).await;
+    }
+  }
+}
+
+#[async_trait]
+pub(super) trait Command: Sync + Send {
+  // Name of the step in the build record, i.e. the name of the command
+  fn name(&self) -> &'static str {
+    std::any::type_name::<Self>().rsplit("::").next().unwrap_or_default()
+  }
+  // The state the deployment is in while this command runs
+  fn state(&self) -> DeploymentState;
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError>;
+  async fn undo(&self, manager: &TransactionManager);
+}
+
+pub(super) struct GitClone;
+#[async_trait]
+impl Command for GitClone {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Cloning
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    let remote = git::parse_remote(manager.repo_url.as_ref().unwrap())?;
+    let auth = git_credentials::resolve(&remote).await?;
+
+    // Full URLs have characters that don't belong in a directory name
+    let directory = format!(
+      "{}_{}_{}",
+      manager.repo_url.as_ref().unwrap().replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "_"),
+      manager.final_branch.as_ref().unwrap().replace('/', "_"),
+      Uuid::new_v4()
+    );
+
+    let base_dir = PathBuf::from(&*DEPLOYMENTS_DIR);
+    if !base_dir.exists() {
+      tokio::fs::create_dir_all(&base_dir).await.map_err(|e| VoyagerError::create_dir(Box::new(e)))?;
+    }
+    
+    let branch = manager.branch.take();
+    let git_ref = manager.deployment.as_ref().unwrap().git_ref.clone();
+    let dir_as_path = base_dir.join(&directory);
+    let output = manager.log_stream.clone();
+    let checkout_path = dir_as_path.clone();
+
+    // libgit2 blocks, and the mirror stays locked until the commit is checked out
+    let commit = tokio::task::spawn_blocking(move || {
+      let mirror = git::fetch(
+        &remote,
+        auth.as_ref(),
+        branch.as_deref(),
+        git_ref.as_deref(),
+        *GIT_FETCH_DEPTH,
+        Path::new(&*REPOSITORIES_DIR),
+        &output,
+      )?;
+      let commit = git::resolve_commit(&mirror.repo, branch.as_deref(), git_ref.as_deref())?;
+
+      let checked_out = git::checkout(&mirror.repo, &commit.sha, &checkout_path, &output);
+      drop(mirror);
+
+      // This step is not undone when it fails, so the checkout is removed here
+      if let Err(e) = checked_out {
+        let _ = fs::remove_dir_all(&checkout_path);
+        return Err(e);
+      }
+
+      Ok(commit)
+    })
+    .await
+    .map_err(|e| VoyagerError::spawn("services::deployments::git_clone", Box::new(e)))??;
+    manager.dir_as_path = Some(dir_as_path);
+
+    manager.log_stream.push(format!("Deploying commit {} by {}", commit.sha, commit.author));
+    manager.commit = Some(commit);
+
+    manager.next = Some(Box::new(PrepareContext));
+
+    Ok(())
+  }
+
+  async fn undo(&self, manager: &TransactionManager) {
+    let dir_as_path = manager.dir_as_path.as_ref().unwrap();
+    if dir_as_path.exists() {
+      let _ = tokio::fs::remove_dir_all(dir_as_path)
+        .await
+        .map_err(|e| VoyagerError::delete_file_or_dir(Box::new(e)));
+    }
+  }
+}
+
+struct PrepareContext;
+#[async_trait]
+impl Command for PrepareContext {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Building
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    manager.log_stream.push("Preparing build context.");
+    let ignore_patterns = tar::read_dockerignore(manager.dir_as_path.as_ref().unwrap())?;
+    if !ignore_patterns.is_empty() {
+      manager.log_stream.push(format!("Excluding files with {} .dockerignore patterns.", ignore_patterns.len()));
+    }
+
+    manager.ignore_patterns = ignore_patterns;
+
+    manager.next = Some(Box::new(CreateImage));
+
+    Ok(())
+  }
+
+  async fn undo(&self, _manager: &TransactionManager) {}
+}
+
+struct CreateImage;
+#[async_trait]
+impl Command for CreateImage {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Building
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    let dockerfile = manager.dir_as_path.as_ref().unwrap().join("Dockerfile");
+    let dockerfile_contents =
+      fs::read_to_string(&dockerfile).map_err(|e| VoyagerError::dockerfile_read(Box::new(e)))?;
+
+    let internal_port = docker::find_internal_port(dockerfile_contents.as_str())?;
+    let traefik_labels = utils::gen_traefik_labels(manager.container_name.as_ref().unwrap(), manager.host.as_ref().unwrap(), internal_port);
+
+    manager.log_stream.push("Building image.");
+    let build_options = &manager.deployment.as_ref().unwrap().build_options;
+    if let Some(target) = build_options.target.as_ref() {
+      manager.log_stream.push(format!("Selecting build target {target}."));
+    }
+    let context = tar::create(manager.dir_as_path.as_ref().unwrap(), manager.ignore_patterns.clone());
+    let image_id = docker::build_image(context, &traefik_labels, None, build_options, &manager.log_stream).await?;
+
+    // manager.internal_port = Some(internal_port);
+    manager.image_id = Some(image_id);
+
+    manager.next = if manager.previous.is_some() {
+      Some(Box::new(RetireContainer))
+    } else {
+      Some(Box::new(CreateContainer))
+    };
+
+    Ok(())
+  }
+  async fn undo(&self, manager: &TransactionManager) {
+    let image_id = manager.image_id.clone().unwrap();
+
+    // A rebuild without changes yields the very same image as the old container or an older build
+    if manager.previous.as_ref().is_some_and(|p| p.image_id.as_ref() == Some(&image_id)) {
+      return;
+    }
+    let deployment_id = manager.deployment.as_ref().unwrap().id.to_hex();
+    if is_image_retained(&deployment_id, &image_id).await {
+      return;
+    }
+
+    let _ = docker::delete_image(image_id).await;
+  }
+}
+
+struct CreateContainer;
+#[async_trait]
+impl Command for CreateContainer {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Starting
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    // Rollbacks reuse an existing image, so there is nothing to clean up
+    if let Some(dir_as_path) = manager.dir_as_path.as_ref() {
+      tokio::fs::remove_dir_all(dir_as_path)
+        .await
+        .map_err(|e| VoyagerError::delete_file_or_dir(Box::new(e)))?;
+    }
+    
+    // let port = get_free_port()?;
+    let deployment = manager.deployment.as_ref().unwrap();
+    let environment = container_environment(&deployment.id.to_hex()).await?;
+    let resource_limits = effective_resource_limits(&deployment.resource_limits, deployment.mode);
+
+    manager.log_stream.push("Creating container.");
+    let container_id =
+      docker::create_container(manager.container_name.clone().unwrap(),/* port, manager.internal_port.unwrap(), */manager.image_id.as_ref().unwrap(), environment, &resource_limits).await?;
+
+    // manager.port = Some(port);
+    manager.container_id = Some(container_id);
+
+    manager.next = Some(Box::new(StartContainer));
+
+    Ok(())
+  }
+
+  async fn undo(&self, manager: &TransactionManager) {
+    let _ = docker::delete_container(manager.container_name.clone().unwrap()).await;
+  }
+}
+
+struct StartContainer;
+#[async_trait]
+impl Command for StartContainer {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Starting
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    manager.log_stream.push("Starting container.");
+    docker::start_container(manager.container_name.clone().unwrap()).await?;
+
+    manager.next = if manager.previous.is_some() {
+      Some(Box::new(UpdateDeployment))
+    } else {
+      Some(Box::new(AddDNSRecord))
+    };
+
+    Ok(())
+  }
+
+  async fn undo(&self, manager: &TransactionManager) {
+    let _ = docker::stop_container(manager.container_name.clone().unwrap()).await;
+  }
+}
+
+struct AddDNSRecord;
+#[async_trait]
+impl Command for AddDNSRecord {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Starting
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    // A failed deployment being redeployed may still have its record
+    let existing = manager.deployment.as_ref().unwrap().dns_record_id.clone();
+    if existing.is_some() {
+      manager.dns_record_id = existing;
+      manager.next = Some(Box::new(SaveDeployment));
+      return Ok(());
+    }
+
+    manager.log_stream.push(format!("Adding DNS record for {}.", manager.host.as_ref().unwrap()));
+    let dns_record_id = DNS_PROVIDER.add_record(manager.host.as_ref().unwrap(), &HOST_IP, manager.mode.unwrap()).await?;
+
+    manager.dns_record_id = dns_record_id;
+
+    manager.next = Some(Box::new(SaveDeployment));
+
+    Ok(())
+  }
+
+  async fn undo(&self, manager: &TransactionManager) {
+    if manager.dns_record_id == manager.deployment.as_ref().unwrap().dns_record_id {
+      return;
+    }
+    if let Some(dns_record_id) = manager.dns_record_id.as_ref() {
+      let _ = DNS_PROVIDER.delete_record(dns_record_id).await;
+    }
+  }
+}
+
+struct SaveDeployment;
+#[async_trait]
+impl Command for SaveDeployment {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Starting
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    let deployment = Deployment {
+      container_id: manager.container_id.take(),
+      dns_record_id: manager.dns_record_id.clone(),
+      image_id: manager.image_id.clone(),
+      commit: manager.commit.clone(),
+      // port: manager.port.take().unwrap(),
+      state: DeploymentState::Deployed,
+      failure_reason: None,
+      updated_at: chrono::Utc::now().timestamp(),
+      ..manager.deployment.clone().unwrap()
+    };
+
+    let deployment_id = deployment.id.to_hex();
+    repositories::deployments::update_release(&deployment).await?;
+
+    manager.final_id = Some(deployment_id);
+    manager.next = None;
+
+    Ok(())
+  }
+
+  // The record itself is kept, and marked as failed by the TransactionManager
+  async fn undo(&self, _manager: &TransactionManager) {}
+}
+
+pub(super) struct RetireContainer;
+#[async_trait]
+impl Command for RetireContainer {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Starting
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    let container_name = manager.container_name.clone().unwrap();
+    let retired_container_name = format!("{container_name}-retired");
+    manager.log_stream.push("Retiring previous container.");
+
+    if docker::is_container_running(container_name.clone()).await? {
+      docker::stop_container(container_name.clone()).await?;
+    }
+    manager.retired_container_name = Some(retired_container_name.clone());
+
+    if let Err(e) = docker::rename_container(container_name.clone(), retired_container_name).await {
+      let _ = docker::start_container(container_name).await;
+      return Err(e);
+    }
+
+    manager.next = Some(Box::new(CreateContainer));
+
+    Ok(())
+  }
+
+  async fn undo(&self, manager: &TransactionManager) {
+    let container_name = manager.container_name.clone().unwrap();
+    let retired_container_name = manager.retired_container_name.clone().unwrap();
+
+    let _ = docker::rename_container(retired_container_name, container_name.clone()).await;
+    if matches!(manager.previous.as_ref().unwrap().state, DeploymentState::Deployed) {
+      let _ = docker::start_container(container_name).await;
+    }
+  }
+}
+
+struct UpdateDeployment;
+#[async_trait]
+impl Command for UpdateDeployment {
+  fn state(&self) -> DeploymentState {
+    DeploymentState::Starting
+  }
+
+  async fn execute(&mut self, manager: &mut TransactionManager) -> Result<(), VoyagerError> {
+    let previous = manager.previous.clone().unwrap();
+    let deployment = Deployment {
+      container_id: manager.container_id.take(),
+      image_id: manager.image_id.clone(),
+      commit: manager.commit.clone(),
+      state: DeploymentState::Deployed,
+      failure_reason: None,
+      updated_at: chrono::Utc::now().timestamp(),
+      ..previous
+    };
+
+    repositories::deployments::update_release(&deployment).await?;
+
+    manager.final_id = Some(previous.id.to_hex());
+    manager.next = None;
+
+    Ok(())
+  }
+
+  async fn undo(&self, manager: &TransactionManager) {
+    let previous = manager.previous.clone().unwrap();
+    let _ = repositories::deployments::update_release(&previous).await;
+  }
+}
+
+impl VoyagerError {
+  fn create_dir(e: Error) -> Self {
+    Self::new(
+      "Failed to create deployments directory".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn delete_file_or_dir(e: Error) -> Self {
+    Self::new(
+      "Failed to delete directory or file for deployment".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn dockerfile_read(e: Error) -> Self {
+    Self::new(
+      "Failed to read Dockerfile contents".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use axum::http::StatusCode;
+use mongodb::bson::oid::ObjectId;
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{
+    model::git_credential::{GitCredential, GitCredentialKind},
+    other::voyager_error::VoyagerError,
+    view::create_git_credential_request::CreateGitCredentialRequest,
+  },
+  utils::{encrypt_secret, runtime_helpers::RuntimeSpawnHandled},
+};
+
+// Secrets are encrypted with SECRETS_KEY, so it has to be set
+pub async fn create(request: CreateGitCredentialRequest) -> Result<GitCredential, VoyagerError> {
+  let CreateGitCredentialRequest { name, host, owner, kind, username, secret, passphrase } = request;
+  event!(Level::INFO, "Creating {kind} git credential {name} for host {host}, owner {owner:?}");
+
+  let name = name.trim().to_string();
+  if name.is_empty() {
+    return Err(VoyagerError::empty_git_credential_name());
+  }
+
+  let host = host.trim().to_lowercase();
+  if host.is_empty() || host.contains(['/', ':', '@']) || host.contains(char::is_whitespace) {
+    return Err(VoyagerError::invalid_git_credential_host(&host));
+  }
+
+  let owner = owner.map(|owner| owner.trim().to_lowercase()).filter(|owner| !owner.is_empty());
+  if owner.as_ref().is_some_and(|owner| owner.contains('/') || owner.contains(char::is_whitespace)) {
+    return Err(VoyagerError::invalid_git_credential_owner());
+  }
+
+  let username = username.map(|username| username.trim().to_string()).filter(|username| !username.is_empty());
+  match kind {
+    GitCredentialKind::Token if username.is_none() => return Err(VoyagerError::missing_git_credential_username()),
+    GitCredentialKind::SshKey if !secret.contains("PRIVATE KEY") => return Err(VoyagerError::invalid_git_ssh_key()),
+    _ if secret.trim().is_empty() => return Err(VoyagerError::empty_git_credential_secret()),
+    _ => {},
+  }
+
+  let git_credential = GitCredential {
+    id: ObjectId::new(),
+    name,
+    host,
+    owner,
+    kind,
+    username: username.filter(|_| kind == GitCredentialKind::Token),
+    secret: encrypt_secret(&secret)?,
+    passphrase: passphrase
+      .filter(|passphrase| kind == GitCredentialKind::SshKey && !passphrase.is_empty())
+      .map(|passphrase| encrypt_secret(&passphrase))
+      .transpose()?,
+    created_at: chrono::Utc::now().timestamp(),
+  };
+
+  let saved = git_credential.clone();
+  SERVICES_RUNTIME
+    .spawn_handled("services::git_credentials::create", repositories::git_credentials::save(saved))
+    .await??;
+
+  event!(Level::INFO, "Git credential created. Id: {}", git_credential.id);
+
+  Ok(git_credential)
+}
+
+impl VoyagerError {
+  fn empty_git_credential_name() -> Self {
+    Self::new(
+      "Git credential name can't be empty".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn invalid_git_credential_host(host: &str) -> Self {
+    Self::new(
+      format!("Invalid git host '{host}', expected a host name without scheme, port nor path, e.g. github.com"),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn invalid_git_credential_owner() -> Self {
+    Self::new(
+      "Git credential owner must be a single organisation or user".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn missing_git_credential_username() -> Self {
+    Self::new(
+      "Token git credentials need a username".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn invalid_git_ssh_key() -> Self {
+    Self::new(
+      "SSH key git credentials need a private key, in PEM or OpenSSH format".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn empty_git_credential_secret() -> Self {
+    Self::new(
+      "Git credential secret can't be empty".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,21 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+pub async fn delete(id: String) -> Result<(), VoyagerError> {
+  event!(Level::INFO, "Deleting git credential. Id: {id}");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::git_credentials::delete", async move {
+      repositories::git_credentials::delete(&id).await
+    })
+    .await?;
+
+  event!(Level::INFO, "Done deleting git credential.");
+
+  result
+}
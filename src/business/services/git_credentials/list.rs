@@ -0,0 +1,19 @@
+use tracing::{event, Level};
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  types::{model::git_credential::GitCredential, other::voyager_error::VoyagerError},
+  utils::runtime_helpers::RuntimeSpawnHandled,
+};
+
+pub async fn list() -> Result<Vec<GitCredential>, VoyagerError> {
+  event!(Level::INFO, "Retrieving git credentials");
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::git_credentials::list", repositories::git_credentials::retrieve_all())
+    .await?;
+
+  event!(Level::DEBUG, "Done retrieving git credentials.");
+
+  result
+}
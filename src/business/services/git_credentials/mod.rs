@@ -0,0 +1,9 @@
+mod create;
+mod delete;
+mod list;
+mod resolve;
+
+pub use create::*;
+pub use delete::*;
+pub use list::*;
+pub use resolve::*;
@@ -0,0 +1,88 @@
+use axum::http::StatusCode;
+use tracing::{event, Level};
+use url::Url;
+
+use crate::{
+  business::{repositories, services::SERVICES_RUNTIME},
+  configs::environment::{GIT_DEFAULT_HOST, GIT_PAT, GIT_SSH_KEY, GIT_USERNAME},
+  types::{
+    model::git_credential::{GitCredential, GitCredentialKind},
+    other::{git_auth::GitAuth, git_remote::GitRemote, voyager_error::VoyagerError},
+  },
+  utils::{decrypt_secret, runtime_helpers::RuntimeSpawnHandled, Error},
+};
+
+// Picks the most recent credential of the repository's owner, then of its whole host.
+// Without any, falls back to GIT_USERNAME and GIT_PAT for GIT_DEFAULT_HOST, or GIT_SSH_KEY for SSH.
+// Plain HTTP repositories are always fetched anonymously
+pub async fn resolve(remote: &GitRemote) -> Result<Option<GitAuth>, VoyagerError> {
+  event!(Level::DEBUG, "Resolving git credentials for {}/{}", remote.host, remote.owner);
+
+  // They would be sent in cleartext
+  if remote.insecure {
+    event!(Level::WARN, "Not using any git credential for {}, as it is not served over HTTPS.", remote.url);
+    return Ok(None);
+  }
+
+  let kind = if remote.ssh { GitCredentialKind::SshKey } else { GitCredentialKind::Token };
+  let (host, owner) = (remote.host.clone(), remote.owner.clone());
+  let credentials = SERVICES_RUNTIME
+    .spawn_handled("services::git_credentials::resolve", async move {
+      repositories::git_credentials::find_by_host(&host, &owner, kind).await
+    })
+    .await??;
+
+  let credential = credentials
+    .iter()
+    .find(|c| c.owner.is_some())
+    .or_else(|| credentials.first());
+  if let Some(credential) = credential {
+    event!(Level::INFO, "Using git credential {} ({}).", credential.name, credential.id);
+    return decrypt(credential).map(Some);
+  }
+
+  if remote.ssh {
+    let Some(path) = GIT_SSH_KEY.as_ref() else {
+      return Ok(None);
+    };
+    let private_key = tokio::fs::read_to_string(path)
+      .await
+      .map_err(|e| VoyagerError::read_git_ssh_key(Box::new(e)))?;
+    return Ok(Some(GitAuth::SshKey { private_key, passphrase: None }));
+  }
+
+  // The global token must not be sent to any other forge
+  let default_host = Url::parse(&GIT_DEFAULT_HOST).ok().and_then(|url| url.host_str().map(str::to_lowercase));
+  match (&*GIT_USERNAME, &*GIT_PAT) {
+    (Some(username), Some(token)) if default_host.as_ref() == Some(&remote.host) => Ok(Some(GitAuth::Token {
+      username: username.clone(),
+      token: token.clone(),
+    })),
+    _ => Ok(None),
+  }
+}
+
+fn decrypt(credential: &GitCredential) -> Result<GitAuth, VoyagerError> {
+  let secret = decrypt_secret(&credential.secret)?;
+  Ok(match credential.kind {
+    GitCredentialKind::Token => GitAuth::Token {
+      username: credential.username.clone().unwrap_or_default(),
+      token: secret,
+    },
+    GitCredentialKind::SshKey => GitAuth::SshKey {
+      private_key: secret,
+      passphrase: credential.passphrase.as_deref().map(decrypt_secret).transpose()?,
+    },
+  })
+}
+
+impl VoyagerError {
+  fn read_git_ssh_key(e: Error) -> Self {
+    Self::new(
+      "Failed to read the SSH key set in GIT_SSH_KEY".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
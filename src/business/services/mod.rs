@@ -1,4 +1,9 @@
+pub mod api_keys;
+pub mod audit;
+pub mod background;
 pub mod deployments;
+pub mod git_credentials;
+pub mod webhooks;
 
 use crate::utils::ExpectError;
 use lazy_static::lazy_static;
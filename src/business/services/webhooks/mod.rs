@@ -0,0 +1,3 @@
+mod push;
+
+pub use push::*;
@@ -0,0 +1,135 @@
+use axum::http::StatusCode;
+use lazy_static::lazy_static;
+use std::collections::HashSet;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tracing::{event, Level};
+
+use crate::business::repositories;
+use crate::business::services::{deployments, SERVICES_RUNTIME};
+use crate::modules::git;
+use crate::types::model::deployment::DeploymentState;
+use crate::types::other::voyager_error::VoyagerError;
+use crate::types::view::push_event::PushEvent;
+use crate::utils::runtime_helpers::RuntimeSpawnHandled;
+
+// How often a deployment that is being deployed is checked, when a push has to wait for it
+const PENDING_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+lazy_static! {
+  // Deployments to redeploy again once their running deploy, redeploy or rollback ends
+  static ref PENDING_REDEPLOYS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+// Finds every deployment tracking the pushed branch and redeploys them in the background.
+// Returns the ids of the deployments that will be redeployed.
+pub async fn push(push_event: PushEvent) -> Result<Vec<String>, VoyagerError> {
+  let repository = push_event.repository;
+  let repo_name = repository.full_name;
+  let Some(branch) = push_event.git_ref.strip_prefix("refs/heads/").map(ToString::to_string) else {
+    event!(Level::INFO, "Ignoring push to non-branch ref {}", push_event.git_ref);
+    return Ok(vec![]);
+  };
+  let is_default_branch = repository.default_branch.as_ref() == Some(&branch);
+
+  // Deployments may have been created from owner/name or from any of the repository's URLs, so they are
+  // compared by the host and path they point to
+  let remotes = [repository.clone_url, repository.ssh_url, repository.html_url]
+    .into_iter()
+    .flatten()
+    .filter_map(|url| git::parse_remote(&url).ok())
+    .map(|remote| (remote.host, remote.path))
+    .collect::<HashSet<_>>();
+
+  event!(Level::INFO, "Received push event. Repo: {repo_name}, branch: {branch}");
+
+  if remotes.is_empty() {
+    event!(Level::INFO, "Ignoring push to {repo_name}, which has no repository URL to match deployments with");
+    return Ok(vec![]);
+  }
+
+  let future = async move {
+    let mut matches = repositories::deployments::find_by_branch(&branch).await?;
+    if is_default_branch {
+      matches.extend(repositories::deployments::find_by_branch("default").await?);
+    }
+    matches.retain(|d| {
+      git::parse_remote(&d.repo_url).is_ok_and(|remote| remotes.contains(&(remote.host, remote.path)))
+    });
+
+    // Deployments pinned to a commit or tag don't follow their branch, and stopped ones stay stopped. Failed or
+    // deleting ones can't be redeployed, while ones being deployed are redeployed once done
+    let ids = matches
+      .into_iter()
+      .filter(|d| {
+        d.git_ref.is_none()
+          && matches!(
+            d.state,
+            DeploymentState::Deployed
+              | DeploymentState::Queued
+              | DeploymentState::Cloning
+              | DeploymentState::Building
+              | DeploymentState::Starting
+          )
+      })
+      .map(|d| d.id.to_hex())
+      .collect::<Vec<_>>();
+
+    let to_redeploy = ids.clone();
+    SERVICES_RUNTIME.spawn(async move {
+      for id in to_redeploy {
+        match deployments::redeploy(id.clone()).await {
+          Ok(_) => {},
+          Err(e) if e.status_code == StatusCode::CONFLICT => defer_redeploy(id),
+          Err(e) => event!(Level::WARN, "Push triggered redeploy of deployment {id} failed: {}", e.message),
+        }
+      }
+    });
+
+    Ok::<Vec<String>, VoyagerError>(ids)
+  };
+
+  let result = SERVICES_RUNTIME
+    .spawn_handled("services::webhooks::push", future)
+    .await?;
+
+  event!(Level::DEBUG, "Done handling push event.");
+
+  result
+}
+
+// A deployment that is being deployed still builds the commit it fetched before the push, so it is redeployed
+// once that ends. Any number of pushes meanwhile only take one more redeploy
+fn defer_redeploy(id: String) {
+  if !PENDING_REDEPLOYS.lock().unwrap_or_else(PoisonError::into_inner).insert(id.clone()) {
+    event!(Level::INFO, "Push triggered redeploy of deployment {id} is already pending.");
+    return;
+  }
+  event!(Level::INFO, "Deployment {id} is being deployed, it will be redeployed for the push once done.");
+
+  SERVICES_RUNTIME.spawn(async move {
+    let redeploy = loop {
+      tokio::time::sleep(PENDING_CHECK_INTERVAL).await;
+      match repositories::deployments::find_by_id(&id).await.map(|d| d.state) {
+        Ok(DeploymentState::Queued | DeploymentState::Cloning | DeploymentState::Building | DeploymentState::Starting) => {},
+        // Failed, stopped or deleted meanwhile, so there is nothing to bring up to date
+        Ok(state) => break state == DeploymentState::Deployed,
+        Err(_) => break false,
+      }
+    };
+    PENDING_REDEPLOYS.lock().unwrap_or_else(PoisonError::into_inner).remove(&id);
+
+    if !redeploy {
+      event!(Level::WARN, "Skipped push triggered redeploy of deployment {id}, as it is no longer deployed.");
+      return;
+    }
+    match deployments::redeploy(id.clone()).await {
+      Ok(_) => {},
+      // Something else started deploying it after the push arrived, so it fetches the pushed commit anyway
+      Err(e) if e.status_code == StatusCode::CONFLICT => {
+        event!(Level::INFO, "Deployment {id} was redeployed meanwhile, skipping push triggered redeploy.");
+      },
+      Err(e) => event!(Level::WARN, "Push triggered redeploy of deployment {id} failed: {}", e.message),
+    }
+  });
+}
@@ -6,14 +6,37 @@ lazy_static! {
   pub static ref HOSTNAME: String = var_opt("HOSTNAME").unwrap_or_else(|| "127.0.0.1".to_string());
   pub static ref PORT: String = var_opt("PORT").unwrap_or_else(|| "8765".to_string());
   pub static ref HOST_IP: String = var("HOST_IP");
+  // Comma separated domains that deployments can be hosted under, the first one being the default
+  pub static ref DOMAINS: Vec<String> = var_opt::<String>("DOMAINS")
+    .unwrap_or_else(|| "lunarlabs.cc".to_string())
+    .split(',')
+    .map(str::trim)
+    .filter(|domain| !domain.is_empty())
+    .map(str::to_lowercase)
+    .collect();
+  // {subdomain} and {domain} are replaced. Without a subdomain, the dash or dot following it is dropped
+  pub static ref PREVIEW_HOST_TEMPLATE: String =
+    var_opt("PREVIEW_HOST_TEMPLATE").unwrap_or_else(|| "{subdomain}-preview.{domain}".to_string());
+  pub static ref PRODUCTION_HOST_TEMPLATE: String =
+    var_opt("PRODUCTION_HOST_TEMPLATE").unwrap_or_else(|| "{subdomain}.{domain}".to_string());
   pub static ref CLOUDFLARE_API_TOKEN: String = var("CLOUDFLARE_API_TOKEN");
   pub static ref CLOUDFLARE_ZONE: String = var("CLOUDFLARE_ZONE");
-  pub static ref API_KEY: String = var("API_KEY");
-  pub static ref DISCORD_WEBHOOK: String = var("DISCORD_WEBHOOK");
-  pub static ref GIT_USERNAME: String = var("GIT_USERNAME");
-  pub static ref GIT_PAT: String = var("GIT_PAT");
+  pub static ref API_KEY: Option<String> = var_opt("API_KEY");
+  // Where owner/name repositories are cloned from
+  pub static ref GIT_DEFAULT_HOST: String =
+    var_opt("GIT_DEFAULT_HOST").unwrap_or_else(|| "https://git.lunarlabs.cc".to_string());
+  // Only sent to GIT_DEFAULT_HOST, for repositories no stored credential matches
+  pub static ref GIT_USERNAME: Option<String> = var_opt("GIT_USERNAME");
+  pub static ref GIT_PAT: Option<String> = var_opt("GIT_PAT");
+  // Path to the private key used for SSH repositories no stored credential matches
+  pub static ref GIT_SSH_KEY: Option<String> = var_opt("GIT_SSH_KEY");
   pub static ref DEPLOYMENTS_DIR: String =
     var_opt("DEPLOYMENTS_DIR").unwrap_or_else(|| "/var/opt/voyager/deployments".to_string());
+  // Bare mirrors of the deployed repositories, fetched incrementally on every deploy
+  pub static ref REPOSITORIES_DIR: String =
+    var_opt("REPOSITORIES_DIR").unwrap_or_else(|| "/var/opt/voyager/repositories".to_string());
+  // Number of commits of a branch to fetch, for HTTPS repositories. Unset for the whole history
+  pub static ref GIT_FETCH_DEPTH: Option<i32> = var_opt("GIT_FETCH_DEPTH");
   pub static ref STDOUT_LOG_SEVERITY: String =
     var_opt("STDOUT_LOG_SEVERITY").unwrap_or_else(|| "INFO".to_string());
   pub static ref LOG_DIRECTORY: String =
@@ -21,6 +44,50 @@ lazy_static! {
   pub static ref MONGO_CONN_STR: String = var("MONGO_CONN_STR");
   pub static ref MONGO_DB_NAME: String = var("MONGO_DB_NAME");
   pub static ref DEVELOPMENT: bool = var_opt("DEVELOPMENT").unwrap_or(false);
+  pub static ref HEALTH_CHECK_INTERVAL: u64 = var_opt("HEALTH_CHECK_INTERVAL").unwrap_or(60);
+  pub static ref WEBHOOK_SECRET: Option<String> = var_opt("WEBHOOK_SECRET");
+  pub static ref SECRETS_KEY: Option<String> = var_opt("SECRETS_KEY");
+  pub static ref PREVIEW_CPUS: Option<f64> = var_opt("PREVIEW_CPUS");
+  pub static ref PREVIEW_MEMORY_MB: Option<i64> = var_opt("PREVIEW_MEMORY_MB");
+  pub static ref PREVIEW_MEMORY_SWAP_MB: Option<i64> = var_opt("PREVIEW_MEMORY_SWAP_MB");
+  pub static ref PREVIEW_PIDS: Option<i64> = var_opt("PREVIEW_PIDS");
+  pub static ref PRODUCTION_CPUS: Option<f64> = var_opt("PRODUCTION_CPUS");
+  pub static ref PRODUCTION_MEMORY_MB: Option<i64> = var_opt("PRODUCTION_MEMORY_MB");
+  pub static ref PRODUCTION_MEMORY_SWAP_MB: Option<i64> = var_opt("PRODUCTION_MEMORY_SWAP_MB");
+  pub static ref PRODUCTION_PIDS: Option<i64> = var_opt("PRODUCTION_PIDS");
+  pub static ref PREVIEW_TTL: Option<i64> = var_opt("PREVIEW_TTL");
+  pub static ref PRODUCTION_TTL: Option<i64> = var_opt("PRODUCTION_TTL");
+  pub static ref REAPER_INTERVAL: u64 = var_opt("REAPER_INTERVAL").unwrap_or(60);
+  // Images of this many of the latest builds of a deployment are kept to roll back to
+  pub static ref IMAGE_RETENTION: usize = var_opt("IMAGE_RETENTION").unwrap_or(3);
+  // cloudflare, rfc2136 or none. Development instances don't manage DNS records by default
+  pub static ref DNS_PROVIDER: String = var_opt("DNS_PROVIDER")
+    .unwrap_or_else(|| if *DEVELOPMENT { "none" } else { "cloudflare" }.to_string());
+  pub static ref RFC2136_SERVER: Option<String> = var_opt("RFC2136_SERVER");
+  pub static ref RFC2136_ZONE: Option<String> = var_opt("RFC2136_ZONE");
+  pub static ref RFC2136_TTL: u32 = var_opt("RFC2136_TTL").unwrap_or(300);
+  pub static ref RFC2136_TSIG_KEY_NAME: Option<String> = var_opt("RFC2136_TSIG_KEY_NAME");
+  pub static ref RFC2136_TSIG_KEY: Option<String> = var_opt("RFC2136_TSIG_KEY");
+  pub static ref RFC2136_TSIG_ALGORITHM: String =
+    var_opt("RFC2136_TSIG_ALGORITHM").unwrap_or_else(|| "hmac-sha256".to_string());
+  // Comma separated channels among discord, webhook and email. Development instances notify nobody by default
+  pub static ref NOTIFIERS: String =
+    var_opt("NOTIFIERS").unwrap_or_else(|| if *DEVELOPMENT { "" } else { "discord" }.to_string());
+  // The *_EVENTS variables restrict a channel to some comma separated events, and default to all of them
+  pub static ref DISCORD_WEBHOOK: Option<String> = var_opt("DISCORD_WEBHOOK");
+  pub static ref DISCORD_EVENTS: Option<String> = var_opt("DISCORD_EVENTS");
+  pub static ref NOTIFY_WEBHOOK_URL: Option<String> = var_opt("NOTIFY_WEBHOOK_URL");
+  pub static ref NOTIFY_WEBHOOK_EVENTS: Option<String> = var_opt("NOTIFY_WEBHOOK_EVENTS");
+  pub static ref SMTP_HOST: Option<String> = var_opt("SMTP_HOST");
+  pub static ref SMTP_PORT: Option<u16> = var_opt("SMTP_PORT");
+  // starttls, tls or none, the latter being meant for local SMTP sinks
+  pub static ref SMTP_TLS: String = var_opt("SMTP_TLS").unwrap_or_else(|| "starttls".to_string());
+  pub static ref SMTP_USERNAME: Option<String> = var_opt("SMTP_USERNAME");
+  pub static ref SMTP_PASSWORD: Option<String> = var_opt("SMTP_PASSWORD");
+  pub static ref SMTP_FROM: Option<String> = var_opt("SMTP_FROM");
+  // Comma separated recipients
+  pub static ref SMTP_TO: Option<String> = var_opt("SMTP_TO");
+  pub static ref SMTP_EVENTS: Option<String> = var_opt("SMTP_EVENTS");
 }
 
 fn var<T: FromStr>(name: &'static str) -> T {
@@ -31,6 +98,11 @@ fn var<T: FromStr>(name: &'static str) -> T {
     .unwrap_or_else(|| panic!("Couldn't parse env variable {name}"))
 }
 
+// Blank variables, as left in .env.example, are unset
 fn var_opt<T: FromStr>(name: &'static str) -> Option<T> {
-  std::env::var(name).ok()?.parse::<T>().ok()
+  std::env::var(name)
+    .ok()
+    .filter(|value| !value.trim().is_empty())?
+    .parse::<T>()
+    .ok()
 }
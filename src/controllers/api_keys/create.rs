@@ -0,0 +1,59 @@
+use axum::{extract::rejection::JsonRejection, http::StatusCode, response::IntoResponse, Json};
+
+use crate::{
+  business::services::api_keys,
+  types::view::{create_api_key::CreateApiKey, create_api_key_request::CreateApiKeyRequest, logs::Logs},
+};
+
+#[utoipa::path(
+  post,
+  path = "/api/v1/api-keys",
+  request_body = CreateApiKeyRequest,
+  responses(
+    (status = 201, description = "Key created, the key itself is only sent in this response", body = CreateApiKey),
+    (status = 400, body = CreateApiKey),
+    (status = 403, description = "Requires the admin scope"),
+  ),
+  tag = "api-keys",
+)]
+pub async fn create(body: Result<Json<CreateApiKeyRequest>, JsonRejection>) -> impl IntoResponse {
+  let body = match body {
+    Ok(Json(body)) => body,
+    Err(e) => return (
+      StatusCode::BAD_REQUEST,
+      Json(CreateApiKey {
+        logs: Logs {
+          message: "Invalid request body".to_string(),
+          errors: vec![e.body_text()],
+        },
+        key: None,
+        api_key: None,
+      }),
+    ),
+  };
+
+  match api_keys::create(body.name, body.scopes, body.ttl).await {
+    Ok((api_key, key)) => (
+      StatusCode::CREATED,
+      Json(CreateApiKey {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        key: Some(key),
+        api_key: Some(api_key.into()),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(CreateApiKey {
+        logs: Logs {
+          message: "Failed to create API key".to_string(),
+          errors: vec![e.message],
+        },
+        key: None,
+        api_key: None,
+      }),
+    ),
+  }
+}
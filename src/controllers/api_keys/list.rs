@@ -0,0 +1,40 @@
+use axum::{http::StatusCode, response::IntoResponse, Json};
+
+use crate::{
+  business::services::api_keys,
+  types::view::{get_api_keys::GetApiKeys, logs::Logs},
+};
+
+#[utoipa::path(
+  get,
+  path = "/api/v1/api-keys",
+  responses(
+    (status = 200, body = GetApiKeys),
+    (status = 403, description = "Requires the admin scope"),
+  ),
+  tag = "api-keys",
+)]
+pub async fn list() -> impl IntoResponse {
+  match api_keys::list().await {
+    Ok(list) => (
+      StatusCode::OK,
+      Json(GetApiKeys {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        api_keys: list.into_iter().map(Into::into).collect(),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(GetApiKeys {
+        logs: Logs {
+          message: "Failed to retrieve API keys".to_string(),
+          errors: vec![e.message],
+        },
+        api_keys: vec![],
+      }),
+    ),
+  }
+}
@@ -0,0 +1,7 @@
+mod create;
+mod list;
+mod revoke;
+
+pub use create::*;
+pub use list::*;
+pub use revoke::*;
@@ -0,0 +1,53 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::api_keys,
+  types::view::{logs::Logs, revoke_api_key::RevokeApiKey},
+};
+
+#[utoipa::path(
+  delete,
+  path = "/api/v1/api-keys/{id}",
+  params(("id" = String, Path, description = "Id of the API key")),
+  responses(
+    (status = 200, body = RevokeApiKey),
+    (status = 403, description = "Requires the admin scope"),
+    (status = 404, description = "Not found or already revoked", body = RevokeApiKey),
+  ),
+  tag = "api-keys",
+)]
+pub async fn revoke(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let Some(id) = queries.get("id").cloned() else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(RevokeApiKey {
+        logs: Logs {
+          message: "apiKeyId is required".to_string(),
+          errors: vec![],
+        },
+      }),
+    );
+  };
+
+  match api_keys::revoke(id).await {
+    Ok(()) => (
+      StatusCode::OK,
+      Json(RevokeApiKey {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(RevokeApiKey {
+        logs: Logs {
+          message: "Failed to revoke API key".to_string(),
+          errors: vec![e.message],
+        },
+      }),
+    ),
+  }
+}
@@ -0,0 +1,79 @@
+use axum::{extract::Query, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::audit,
+  controllers::parse_query,
+  types::{
+    other::audit_log_filter::AuditLogFilter,
+    view::{get_audit_logs::GetAuditLogs, logs::Logs},
+  },
+};
+
+#[utoipa::path(
+  get,
+  path = "/api/v1/audit",
+  params(
+    ("apiKeyId" = Option<String>, Query, description = "Only calls made with this API key"),
+    ("action" = Option<String>, Query, description = "Only this action, e.g. deployment.delete"),
+    ("targetId" = Option<String>, Query, description = "Only calls on this deployment or API key"),
+    ("since" = Option<i64>, Query, description = "Unix timestamp of the oldest call"),
+    ("until" = Option<i64>, Query, description = "Unix timestamp of the newest call"),
+    ("limit" = Option<i64>, Query, description = "Number of calls, 100 by default and 1000 at most"),
+  ),
+  responses(
+    (status = 200, body = GetAuditLogs),
+    (status = 400, body = GetAuditLogs),
+    (status = 403, description = "Requires the admin scope"),
+  ),
+  tag = "audit",
+)]
+pub async fn list(Query(queries): Query<HashMap<String, String>>) -> impl IntoResponse {
+  let filter = match parse_filter(&queries) {
+    Ok(filter) => filter,
+    Err(message) => return (
+      StatusCode::BAD_REQUEST,
+      Json(GetAuditLogs {
+        logs: Logs {
+          message,
+          errors: vec![],
+        },
+        audit_logs: vec![],
+      }),
+    ),
+  };
+
+  match audit::list(filter).await {
+    Ok(audit_logs) => (
+      StatusCode::OK,
+      Json(GetAuditLogs {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        audit_logs,
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(GetAuditLogs {
+        logs: Logs {
+          message: "Failed to retrieve audit logs".to_string(),
+          errors: vec![e.message],
+        },
+        audit_logs: vec![],
+      }),
+    ),
+  }
+}
+
+fn parse_filter(queries: &HashMap<String, String>) -> Result<AuditLogFilter, String> {
+  Ok(AuditLogFilter {
+    api_key_id: queries.get("apiKeyId").cloned(),
+    action: queries.get("action").cloned(),
+    target_id: queries.get("targetId").cloned(),
+    since: parse_query(queries, "since")?,
+    until: parse_query(queries, "until")?,
+    limit: parse_query(queries, "limit")?,
+  })
+}
@@ -0,0 +1,162 @@
+use axum::{
+  body::{to_bytes, Body, HttpBody},
+  extract::{MatchedPath, Path, Query, Request},
+  http::{Method, StatusCode},
+  middleware::Next,
+  response::{IntoResponse, Response},
+  Extension,
+};
+use mongodb::bson::oid::ObjectId;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::{
+  business::services::audit,
+  types::{
+    model::audit_log::{AuditLog, AuditOutcome, AuditParams},
+    other::api_key_identity::ApiKeyIdentity,
+  },
+};
+
+// Larger bodies, such as webhook payloads, are passed along without being read, and are not kept in the audit log
+const MAX_AUDITED_BODY_SIZE: usize = 16 * 1024;
+const REDACTED_KEYS: [&str; 4] = ["value", "secret", "passphrase", "buildArgs"];
+// Build arguments given as query parameters, as buildArg.NAME
+const REDACTED_QUERY_PREFIX: &str = "buildArg.";
+
+// Records every call that is not a GET into the audit log, once it has been answered
+pub(super) async fn audit_middleware(
+  identity: Option<Extension<ApiKeyIdentity>>,
+  matched_path: Option<MatchedPath>,
+  path: Option<Path<HashMap<String, String>>>,
+  Query(query): Query<HashMap<String, String>>,
+  request: Request,
+  next: Next,
+) -> Response {
+  if request.method() == Method::GET {
+    return next.run(request).await;
+  }
+
+  let method = request.method().clone();
+  let uri_path = request.uri().path().to_string();
+  let (parts, body) = request.into_parts();
+  let (body, audited_body) = if is_audited(&body) {
+    let Ok(bytes) = to_bytes(body, MAX_AUDITED_BODY_SIZE).await else {
+      return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+    let audited_body = serde_json::from_slice::<Value>(&bytes).ok().map(redact);
+    (Body::from(bytes), audited_body)
+  } else {
+    (body, None)
+  };
+
+  let response = next.run(Request::from_parts(parts, body)).await;
+
+  // Only the id and the errors are needed from the response, and the JSON answers of the handlers are small
+  let (parts, body) = response.into_parts();
+  let (body, response_body) = if is_audited(&body) {
+    let Ok(bytes) = to_bytes(body, MAX_AUDITED_BODY_SIZE).await else {
+      return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let response_body = serde_json::from_slice::<Value>(&bytes).unwrap_or_default();
+    (Body::from(bytes), response_body)
+  } else {
+    (body, Value::Null)
+  };
+
+  let path = path.map(|Path(path)| path).unwrap_or_default();
+  let (api_key_id, api_key_name) = identity.map_or_else(
+    || (None, "webhook".to_string()),
+    |Extension(identity)| (identity.id, identity.name),
+  );
+  let matched_path = matched_path.map_or_else(|| uri_path.clone(), |p| p.as_str().to_string());
+
+  audit::record(AuditLog {
+    id: ObjectId::new(),
+    api_key_id,
+    api_key_name,
+    action: action(&method, &matched_path),
+    // Created deployments only have an id once answered
+    target_id: path
+      .get("id")
+      .cloned()
+      .or_else(|| response_body["id"].as_str().map(str::to_string)),
+    method: method.to_string(),
+    path: uri_path,
+    params: AuditParams {
+      path,
+      query: redact_query(query),
+      body: audited_body,
+    },
+    outcome: AuditOutcome {
+      status: parts.status.as_u16(),
+      succeeded: parts.status.is_success(),
+      errors: response_body["logs"]["errors"]
+        .as_array()
+        .map(|errors| errors.iter().filter_map(|e| e.as_str().map(str::to_string)).collect())
+        .unwrap_or_default(),
+    },
+    created_at: chrono::Utc::now().timestamp(),
+  })
+  .await;
+
+  Response::from_parts(parts, body)
+}
+
+// Bodies of unknown length, such as chunked ones, are not read either
+fn is_audited(body: &Body) -> bool {
+  body.size_hint().upper().and_then(|size| usize::try_from(size).ok()).is_some_and(|size| size <= MAX_AUDITED_BODY_SIZE)
+}
+
+fn action(method: &Method, matched_path: &str) -> String {
+  let path = matched_path.trim_start_matches("/api/v1").trim_end_matches('/');
+  let action = match (method.as_str(), path) {
+    ("POST", "/deployments") => "deployment.create",
+    ("DELETE", "/deployments/:id") => "deployment.delete",
+    ("POST", "/deployments/:id/redeploy") => "deployment.redeploy",
+    ("POST", "/deployments/:id/rollback") => "deployment.rollback",
+    ("POST", "/deployments/:id/stop") => "deployment.stop",
+    ("POST", "/deployments/:id/start") => "deployment.start",
+    ("POST", "/deployments/:id/restart") => "deployment.restart",
+    ("POST", "/deployments/:id/extend") => "deployment.extend",
+    ("PUT", "/deployments/:id/build-options") => "deployment.build-options",
+    ("PUT", "/deployments/:id/resources") => "deployment.resources",
+    ("PUT", "/deployments/:id/env/:name") => "environment.set",
+    ("DELETE", "/deployments/:id/env/:name") => "environment.delete",
+    ("POST", "/api-keys") => "api-key.create",
+    ("DELETE", "/api-keys/:id") => "api-key.revoke",
+    ("POST", "/git-credentials") => "git-credential.create",
+    ("DELETE", "/git-credentials/:id") => "git-credential.delete",
+    ("POST", "/webhooks/push") => "webhook.push",
+    _ => return format!("{method} {matched_path}"),
+  };
+
+  action.to_string()
+}
+
+// Environment variable values, build arguments and git credentials may be secrets, so they are never kept
+fn redact(value: Value) -> Value {
+  match value {
+    Value::Object(object) => Value::Object(
+      object
+        .into_iter()
+        .map(|(key, value)| {
+          let value = if REDACTED_KEYS.contains(&key.as_str()) { Value::String("[redacted]".to_string()) } else { redact(value) };
+          (key, value)
+        })
+        .collect(),
+    ),
+    Value::Array(array) => Value::Array(array.into_iter().map(redact).collect()),
+    value => value,
+  }
+}
+
+fn redact_query(query: HashMap<String, String>) -> HashMap<String, String> {
+  query
+    .into_iter()
+    .map(|(key, value)| {
+      let value = if key.starts_with(REDACTED_QUERY_PREFIX) { "[redacted]".to_string() } else { value };
+      (key, value)
+    })
+    .collect()
+}
@@ -0,0 +1,90 @@
+use axum::{
+  extract::{Path, Request, State},
+  http::StatusCode,
+  middleware::Next,
+  response::{IntoResponse, Json, Response},
+  Extension,
+};
+use std::collections::HashMap;
+
+use super::BasicResponse;
+use crate::{
+  business::services::{api_keys, deployments},
+  types::{
+    model::api_key::ApiKeyScope,
+    other::api_key_identity::ApiKeyIdentity,
+    view::logs::Logs,
+  },
+};
+
+// Identifies the caller by its X-Api-Key, for the scope checks and handlers that follow
+pub(super) async fn authorization_middleware(mut request: Request, next: Next) -> Response {
+  let api_key = request
+    .headers()
+    .get("X-Api-Key")
+    .and_then(|h| h.to_str().ok())
+    .map(str::to_string);
+
+  let Some(api_key) = api_key else {
+    return error_response(StatusCode::UNAUTHORIZED, "Unauthorized", "Missing API Key".to_string());
+  };
+
+  match api_keys::authenticate(api_key).await {
+    Ok(identity) => {
+      request.extensions_mut().insert(identity);
+      next.run(request).await
+    },
+    Err(e) => error_response(e.status_code, "Unauthorized", e.message),
+  }
+}
+
+pub(super) async fn require_scope(
+  State(scope): State<ApiKeyScope>,
+  Extension(identity): Extension<ApiKeyIdentity>,
+  request: Request,
+  next: Next,
+) -> Response {
+  if identity.has_scope(scope) {
+    next.run(request).await
+  } else {
+    forbidden(format!("API key lacks the '{scope}' scope"))
+  }
+}
+
+// Changing a deployment needs the deploy scope of its mode. When it can't be found, the handler reports it
+pub(super) async fn require_deploy_scope(
+  Path(path): Path<HashMap<String, String>>,
+  Extension(identity): Extension<ApiKeyIdentity>,
+  request: Request,
+  next: Next,
+) -> Response {
+  let allowed = match path.get("id") {
+    Some(id) => match deployments::get(id.clone()).await {
+      Ok(deployment) => identity.can_deploy(deployment.mode),
+      Err(_) => identity.has_scope(ApiKeyScope::DeployPreview) || identity.has_scope(ApiKeyScope::DeployProduction),
+    },
+    None => false,
+  };
+
+  if allowed {
+    next.run(request).await
+  } else {
+    forbidden("API key can't change this deployment".to_string())
+  }
+}
+
+fn forbidden(error: String) -> Response {
+  error_response(StatusCode::FORBIDDEN, "Forbidden", error)
+}
+
+fn error_response(status_code: StatusCode, message: &str, error: String) -> Response {
+  let mut response = Json(BasicResponse {
+    logs: Logs {
+      message: message.to_string(),
+      errors: vec![error],
+    },
+  })
+  .into_response();
+  *response.status_mut() = status_code;
+  response
+}
@@ -1,88 +1,97 @@
-use axum::{extract::Query, http::StatusCode, response::IntoResponse, Json};
+use axum::{body::Bytes, extract::Query, http::StatusCode, Extension, Json};
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
   business::services::deployments,
+  controllers::parse_query,
+  modules::git,
+  configs::environment::{DOMAINS, PREVIEW_HOST_TEMPLATE, PRODUCTION_HOST_TEMPLATE},
   types::{
-    model::deployment::Mode, view::{create_deployment::CreateDeployment, logs::Logs}
+    model::deployment::{BuildOptions, Mode, ResourceLimits},
+    other::{api_key_identity::ApiKeyIdentity, new_deployment::NewDeployment},
+    view::{
+      create_deployment::CreateDeployment, create_deployment_request::CreateDeploymentRequest,
+      field_error::FieldError, logs::Logs,
+    },
   },
 };
 
-pub async fn create(Query(queries): Query<HashMap<String, String>>) -> impl IntoResponse {
-  let mode = queries.get("mode");
-  let repo_url = queries.get("repoUrl");
-  let subdomain = queries.get("subdomain");
+// Placeholders of the host templates
+const SUBDOMAIN: &str = "{subdomain}";
+const DOMAIN: &str = "{domain}";
 
-  // Validations
-  let mode = match mode.map(|s| s.as_str()) {
-    Some("production") => Mode::Production,
-    Some("preview") => Mode::Preview,
-    Some(_) => {
-      return (
-        StatusCode::BAD_REQUEST,
-        Json(CreateDeployment {
-          logs: Logs {
-            message: "Mode must be either 'production' or 'preview'".to_string(),
-            errors: vec![],
-          },
-          id: None,
-        }),
-      );
-    },
-    None => return (
-      StatusCode::BAD_REQUEST,
-      Json(CreateDeployment {
-        logs: Logs {
-          message: "Mode is required".to_string(),
-          errors: vec![],
-        },
-        id: None,
-      }),
-    ),
+// Takes a JSON body, or query parameters when there is none, which is how deployments used to be created
+#[utoipa::path(
+  post,
+  path = "/api/v1/deployments",
+  params(
+    ("repoUrl" = Option<String>, Query, description = "Repository to deploy, when there is no body, optionally followed by @branch"),
+    ("branch" = Option<String>, Query, description = "Branch to deploy, instead of an @branch suffix. Defaults to the default branch"),
+    ("ref" = Option<String>, Query, description = "Commit SHA or tag to deploy, instead of the head of the branch"),
+    ("mode" = Option<String>, Query, description = "preview or production"),
+    ("subdomain" = Option<String>, Query, description = "Subdomain to deploy to, or the domain itself when left out"),
+    ("domain" = Option<String>, Query, description = "One of the configured domains, defaults to the first one"),
+    ("ttl" = Option<i64>, Query, description = "Seconds until the deployment expires, 0 for never"),
+    ("buildArg.NAME" = Option<String>, Query, description = "Build argument NAME passed to the Docker build, for any NAME"),
+    ("target" = Option<String>, Query, description = "Stage of a multi-stage Dockerfile to build, defaults to the last one"),
+    ("noCache" = Option<bool>, Query, description = "Build without using cached layers"),
+    ("cpus" = Option<f64>, Query, description = "Number of CPUs the container can use"),
+    ("memoryMb" = Option<i64>, Query, description = "Memory limit of the container, in MiB"),
+    ("memorySwapMb" = Option<i64>, Query, description = "Memory plus swap limit of the container, in MiB, -1 for unlimited swap"),
+    ("pids" = Option<i64>, Query, description = "Maximum number of processes in the container, -1 for unlimited"),
+  ),
+  request_body(content = CreateDeploymentRequest, description = "Takes precedence over the query parameters"),
+  responses(
+    (status = 202, description = "Deployment queued", body = CreateDeployment),
+    (status = 400, description = "Invalid parameters", body = CreateDeployment),
+    (status = 403, description = "API key can't deploy in this mode", body = CreateDeployment),
+  ),
+  tag = "deployments",
+)]
+pub async fn create(
+  Extension(identity): Extension<ApiKeyIdentity>,
+  Query(queries): Query<HashMap<String, String>>,
+  body: Bytes,
+) -> (StatusCode, Json<CreateDeployment>) {
+  let new_deployment = if body.is_empty() {
+    from_query(&queries).map_err(|message| bad_request(message, vec![]))
+  } else {
+    from_body(&body).map_err(|errors| bad_request("Invalid request body".to_string(), errors))
+  };
+  let new_deployment = match new_deployment {
+    Ok(new_deployment) => new_deployment,
+    Err(response) => return response,
   };
-  let Some(repo_url) = repo_url else {
+  // The scope needed depends on the mode, so it can only be checked here
+  if !identity.can_deploy(new_deployment.mode) {
     return (
-      StatusCode::BAD_REQUEST,
+      StatusCode::FORBIDDEN,
       Json(CreateDeployment {
         logs: Logs {
-          message: "repoUrl is required".to_string(),
-          errors: vec![],
+          message: "Forbidden".to_string(),
+          errors: vec![format!("API key can't deploy in {} mode", new_deployment.mode)],
         },
         id: None,
+        validation_errors: vec![],
       }),
     );
-  };
-  let host = match resolve_host(subdomain, &mode) {
-    None => return (
-      StatusCode::BAD_REQUEST,
-      Json(CreateDeployment {
-        logs: Logs {
-          message: "Subdomains can only have alphanumerics, underscore and dashes, and can only start with alphanumerics.".to_string(),
-          errors: vec![],
-        },
-        id: None,
-      }),
-    ),
-    Some(host) => host
-  };
-
-  let split = repo_url.split('@').collect::<Vec<_>>();
-  let repo_url = split[0].to_string();
-  let branch = split.get(1).map(std::string::ToString::to_string);
+  }
 
   match async {
-    deployments::check(&host, mode, repo_url.clone(), branch.clone()).await?;
-    deployments::new(host, mode, repo_url, branch).await
+    let NewDeployment { host, mode, repo_url, branch, .. } = &new_deployment;
+    deployments::check(host, *mode, repo_url.clone(), branch.clone()).await?;
+    deployments::new(new_deployment).await
   }.await {
     Ok(deployment_id) => (
-      StatusCode::OK,
+      StatusCode::ACCEPTED,
       Json(CreateDeployment {
         logs: Logs {
           message: "Success!".to_string(),
           errors: vec![],
         },
         id: Some(deployment_id),
+        validation_errors: vec![],
       }),
     ),
     Err(e) => (
@@ -93,12 +102,224 @@ pub async fn create(Query(queries): Query<HashMap<String, String>>) -> impl Into
           errors: vec![e.message],
         },
         id: None,
+        validation_errors: vec![],
       }),
     ),
   }
 }
 
-fn resolve_host(subdomain: Option<&String>, mode: &Mode) -> Option<String> {
+fn bad_request(message: String, validation_errors: Vec<FieldError>) -> (StatusCode, Json<CreateDeployment>) {
+  (
+    StatusCode::BAD_REQUEST,
+    Json(CreateDeployment {
+      logs: Logs {
+        message,
+        errors: validation_errors.iter().map(|e| format!("{}: {}", e.field, e.message)).collect(),
+      },
+      id: None,
+      validation_errors,
+    }),
+  )
+}
+
+fn from_body(body: &[u8]) -> Result<NewDeployment, Vec<FieldError>> {
+  let request = serde_json::from_slice::<CreateDeploymentRequest>(body)
+    .map_err(|e| vec![field_error("body", e.to_string())])?;
+
+  let mut errors = Vec::new();
+
+  let repo_url = request.repo.filter(|r| !r.is_empty());
+  match repo_url.as_ref() {
+    None => errors.push(field_error("repo", "is required")),
+    Some(r) if r.contains(char::is_whitespace) || split_branch(r).1.is_some() => {
+      errors.push(field_error("repo", "cannot have whitespaces nor a '@branch' suffix, use branch instead"));
+    },
+    Some(r) => {
+      if let Err(e) = git::parse_remote(r) {
+        errors.push(field_error("repo", e.message));
+      }
+    },
+  }
+
+  if request.branch.as_ref().is_some_and(|b| b.is_empty() || b.contains(char::is_whitespace)) {
+    errors.push(field_error("branch", "cannot be empty nor have whitespaces"));
+  }
+
+  if request.git_ref.as_ref().is_some_and(|r| !is_valid_ref(r)) {
+    errors.push(field_error("ref", "must be a commit SHA or a tag, without whitespaces"));
+  }
+
+  let mode = match request.mode.as_deref() {
+    Some("production") => Some(Mode::Production),
+    Some("preview") => Some(Mode::Preview),
+    Some(_) => {
+      errors.push(field_error("mode", "must be either 'production' or 'preview'"));
+      None
+    },
+    None => {
+      errors.push(field_error("mode", "is required"));
+      None
+    },
+  };
+
+  let domain = resolve_domain(request.domain.as_ref());
+  if domain.is_none() {
+    errors.push(field_error("domain", format!("must be one of {}", DOMAINS.join(", "))));
+  }
+
+  // The host can only be resolved once the mode and domain are known
+  let host = mode.zip(domain).and_then(|(mode, domain)| {
+    let host = resolve_host(request.subdomain.as_ref(), domain, &mode);
+    if host.is_none() {
+      errors.push(field_error("subdomain", "can only have alphanumerics, underscore and dashes, and can only start with alphanumerics"));
+    }
+    host
+  });
+
+  let mut names = HashSet::new();
+  for (i, variable) in request.env.iter().enumerate() {
+    if let Err(e) = deployments::validate_environment_variable_name(&variable.name) {
+      errors.push(field_error(&format!("env[{i}].name"), e.message));
+    } else if !names.insert(variable.name.as_str()) {
+      errors.push(field_error(&format!("env[{i}].name"), format!("'{}' is set more than once", variable.name)));
+    }
+  }
+
+  let resource_limits = ResourceLimits::from(request.resources);
+  if let Err(e) = deployments::validate_resource_limits(&resource_limits) {
+    errors.push(field_error("resources", e.message));
+  }
+
+  let build_options = BuildOptions::from(request.build);
+  if let Err(e) = deployments::validate_build_options(&build_options) {
+    errors.push(field_error("build", e.message));
+  }
+
+  if request.ttl.is_some_and(|ttl| ttl < 0) {
+    errors.push(field_error("ttl", "cannot be negative"));
+  }
+
+  match (repo_url, mode, host) {
+    (Some(repo_url), Some(mode), Some(host)) if errors.is_empty() => Ok(NewDeployment {
+      host,
+      mode,
+      repo_url,
+      branch: request.branch,
+      git_ref: request.git_ref,
+      environment: request.env,
+      build_options,
+      resource_limits,
+      ttl: request.ttl,
+    }),
+    _ => Err(errors),
+  }
+}
+
+fn field_error(field: &str, message: impl Into<String>) -> FieldError {
+  FieldError {
+    field: field.to_string(),
+    message: message.into(),
+  }
+}
+
+fn from_query(queries: &HashMap<String, String>) -> Result<NewDeployment, String> {
+  let mode = match queries.get("mode").map(String::as_str) {
+    Some("production") => Mode::Production,
+    Some("preview") => Mode::Preview,
+    Some(_) => return Err("Mode must be either 'production' or 'preview'".to_string()),
+    None => return Err("Mode is required".to_string()),
+  };
+  let Some(repo_url) = queries.get("repoUrl") else {
+    return Err("repoUrl is required".to_string());
+  };
+  let Some(domain) = resolve_domain(queries.get("domain")) else {
+    return Err(format!("Domain must be one of {}", DOMAINS.join(", ")));
+  };
+  let Some(host) = resolve_host(queries.get("subdomain"), domain, &mode) else {
+    return Err("Subdomains can only have alphanumerics, underscore and dashes, and can only start with alphanumerics.".to_string());
+  };
+
+  let (build_options, resource_limits, ttl) = parse_options(queries)?;
+
+  let (repo_url, branch) = match (split_branch(repo_url), queries.get("branch")) {
+    ((_, Some(_)), Some(_)) => return Err("The branch can't be given both in repoUrl and as branch".to_string()),
+    ((repo_url, branch), None) => (repo_url, branch),
+    ((repo_url, None), Some(branch)) => (repo_url, Some(branch.clone()).filter(|b| !b.is_empty())),
+  };
+  git::parse_remote(&repo_url).map_err(|e| e.message)?;
+  let git_ref = queries.get("ref").cloned();
+  if git_ref.as_ref().is_some_and(|r| !is_valid_ref(r)) {
+    return Err("ref must be a commit SHA or a tag, without whitespaces".to_string());
+  }
+
+  Ok(NewDeployment {
+    host,
+    mode,
+    repo_url,
+    branch,
+    git_ref,
+    environment: vec![],
+    build_options,
+    resource_limits,
+    ttl,
+  })
+}
+
+// The branch follows the last '@' of the path, as SSH URLs also have one before their host
+fn split_branch(repo_url: &str) -> (String, Option<String>) {
+  let path_start = repo_url
+    .find("://")
+    .map(|i| repo_url[i + 3..].find('/').map_or(repo_url.len(), |j| i + 3 + j))
+    .or_else(|| repo_url.find(':'))
+    .unwrap_or(0);
+
+  repo_url[path_start..].rfind('@').map_or_else(
+    || (repo_url.to_string(), None),
+    |i| {
+      let branch = &repo_url[path_start + i + 1..];
+      (repo_url[..path_start + i].to_string(), Some(branch.to_string()).filter(|b| !b.is_empty()))
+    },
+  )
+}
+
+// Ranges and other revision syntax, which could resolve to anything, are not accepted
+fn is_valid_ref(git_ref: &str) -> bool {
+  !git_ref.is_empty()
+    && !git_ref.starts_with('-')
+    && !git_ref.contains(char::is_whitespace)
+    && !git_ref.contains(['~', '^', ':', '@', '{', '}', '?', '*', '[', '\\'])
+    && !git_ref.contains("..")
+}
+
+// Build arguments are given as buildArg.NAME=value
+fn parse_options(queries: &HashMap<String, String>) -> Result<(BuildOptions, ResourceLimits, Option<i64>), String> {
+  let build_options = BuildOptions {
+    build_args: queries
+      .iter()
+      .filter_map(|(k, v)| k.strip_prefix("buildArg.").map(|k| (k.to_string(), v.clone())))
+      .collect(),
+    target: queries.get("target").cloned(),
+    no_cache: parse_query(queries, "noCache")?.unwrap_or(false),
+  };
+
+  let resource_limits = ResourceLimits {
+    cpus: parse_query(queries, "cpus")?,
+    memory_mb: parse_query(queries, "memoryMb")?,
+    memory_swap_mb: parse_query(queries, "memorySwapMb")?,
+    pids: parse_query(queries, "pids")?,
+  };
+
+  Ok((build_options, resource_limits, parse_query(queries, "ttl")?))
+}
+
+// Defaults to the first configured domain
+fn resolve_domain(domain: Option<&String>) -> Option<&'static str> {
+  domain
+    .map_or_else(|| DOMAINS.first(), |domain| DOMAINS.iter().find(|d| d.eq_ignore_ascii_case(domain)))
+    .map(String::as_str)
+}
+
+fn resolve_host(subdomain: Option<&String>, domain: &str, mode: &Mode) -> Option<String> {
   let subdomain = subdomain.map(|s| s.as_ref()).unwrap_or_default();
   
   // Validates the subdomain
@@ -112,19 +333,18 @@ fn resolve_host(subdomain: Option<&String>, mode: &Mode) -> Option<String> {
   }
 
   // Processes the hostname
-  let mut host = if !subdomain.is_empty() {
-    if matches!(mode, Mode::Preview) {
-      format!("{subdomain}-")
-    } else {
-      format!("{subdomain}.")
-    }
+  let template = match mode {
+    Mode::Preview => &*PREVIEW_HOST_TEMPLATE,
+    Mode::Production => &*PRODUCTION_HOST_TEMPLATE,
+  };
+  let host = if subdomain.is_empty() {
+    // The dash or dot that follows the subdomain goes along with it
+    ["-", ".", ""]
+      .iter()
+      .fold(template.clone(), |host, separator| host.replace(&format!("{SUBDOMAIN}{separator}"), ""))
   } else {
-    String::new()
+    template.replace(SUBDOMAIN, subdomain)
   };
-  if matches!(mode, Mode::Preview) {
-    host = format!("{host}preview.");
-  }
-  host = format!("{host}lunarlabs.cc");
 
-  Some(host)
+  Some(host.replace(DOMAIN, domain))
 }
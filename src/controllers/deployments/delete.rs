@@ -6,6 +6,16 @@ use crate::{
   types::view::{delete_deployment::DeleteDeployment, logs::Logs},
 };
 
+#[utoipa::path(
+  delete,
+  path = "/api/v1/deployments/{id}",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  responses(
+    (status = 200, body = DeleteDeployment),
+    (status = 404, body = DeleteDeployment),
+  ),
+  tag = "deployments",
+)]
 pub async fn delete(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
   let id_opt = queries.get("id").cloned();
 
@@ -0,0 +1,55 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{logs::Logs, update_environment::UpdateEnvironment},
+};
+
+#[utoipa::path(
+  delete,
+  path = "/api/v1/deployments/{id}/env/{name}",
+  params(
+    ("id" = String, Path, description = "Id of the deployment"),
+    ("name" = String, Path, description = "Name of the variable"),
+  ),
+  responses(
+    (status = 200, body = UpdateEnvironment),
+    (status = 404, body = UpdateEnvironment),
+  ),
+  tag = "deployments",
+)]
+pub async fn delete_environment_variable(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let (Some(id), Some(name)) = (queries.get("id").cloned(), queries.get("name").cloned()) else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(UpdateEnvironment {
+        logs: Logs {
+          message: "deploymentId and name are required".to_string(),
+          errors: vec![],
+        },
+      }),
+    );
+  };
+
+  match deployments::delete_environment_variable(id, name).await {
+    Ok(()) => (
+      StatusCode::OK,
+      Json(UpdateEnvironment {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(UpdateEnvironment {
+        logs: Logs {
+          message: "Failed to delete environment variable".to_string(),
+          errors: vec![e.message],
+        },
+      }),
+    ),
+  }
+}
@@ -0,0 +1,82 @@
+use axum::{
+  extract::{Path, Query},
+  http::StatusCode,
+  response::IntoResponse,
+  Json,
+};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{extend_deployment::ExtendDeployment, logs::Logs},
+};
+
+#[utoipa::path(
+  post,
+  path = "/api/v1/deployments/{id}/extend",
+  params(
+    ("id" = String, Path, description = "Id of the deployment"),
+    ("ttl" = Option<i64>, Query, description = "Seconds from now until the deployment expires, 0 for never"),
+  ),
+  responses(
+    (status = 200, body = ExtendDeployment),
+    (status = 400, body = ExtendDeployment),
+    (status = 404, body = ExtendDeployment),
+  ),
+  tag = "deployments",
+)]
+pub async fn extend(
+  Path(path): Path<HashMap<String, String>>,
+  Query(queries): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+  let id_opt = path.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(ExtendDeployment {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+        expires_at: None,
+      }),
+    );
+  };
+
+  let Ok(ttl) = queries.get("ttl").map(|t| t.parse::<i64>()).transpose() else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(ExtendDeployment {
+        logs: Logs {
+          message: "ttl must be a number of seconds".to_string(),
+          errors: vec![],
+        },
+        expires_at: None,
+      }),
+    );
+  };
+
+  match deployments::extend(id, ttl).await {
+    Ok(expires_at) => (
+      StatusCode::OK,
+      Json(ExtendDeployment {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        expires_at,
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(ExtendDeployment {
+        logs: Logs {
+          message: "Failed to extend deployment".to_string(),
+          errors: vec![e.message],
+        },
+        expires_at: None,
+      }),
+    ),
+  }
+}
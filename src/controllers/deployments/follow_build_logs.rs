@@ -0,0 +1,79 @@
+use axum::{
+  extract::Path,
+  http::StatusCode,
+  response::{
+    sse::{Event, KeepAlive, Sse},
+    IntoResponse, Response,
+  },
+  Json,
+};
+use futures::{stream, StreamExt};
+use std::{collections::HashMap, convert::Infallible};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+  business::services::deployments,
+  types::view::{follow_build_logs::FollowBuildLogs, logs::Logs},
+};
+
+// Streams the clone, build and start output of a deployment as Server-Sent Events, one event per line.
+// Lines already output are sent first, and the stream ends when the deployment is done.
+#[utoipa::path(
+  get,
+  path = "/api/v1/deployments/{id}/build-logs",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  responses(
+    (status = 200, description = "Server-Sent Events, one per line of output", content_type = "text/event-stream", body = String),
+    (status = 404, body = FollowBuildLogs),
+  ),
+  tag = "deployments",
+)]
+pub async fn follow_build_logs(Path(queries): Path<HashMap<String, String>>) -> Response {
+  let id_opt = queries.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(FollowBuildLogs {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+      }),
+    )
+      .into_response();
+  };
+
+  let (lines, receiver) = match deployments::follow_build_logs(&id) {
+    Ok(subscription) => subscription,
+    Err(e) => {
+      return (
+        e.status_code,
+        Json(FollowBuildLogs {
+          logs: Logs {
+            message: "Failed to follow build logs".to_string(),
+            errors: vec![e.message],
+          },
+        }),
+      )
+        .into_response()
+    },
+  };
+
+  let following = stream::unfold(receiver, |mut receiver| async move {
+    loop {
+      match receiver.recv().await {
+        Ok(line) => return Some((line, receiver)),
+        // Lines missed by a slow client are skipped
+        Err(RecvError::Lagged(_)) => {},
+        Err(RecvError::Closed) => return None,
+      }
+    }
+  });
+
+  let events = stream::iter(lines)
+    .chain(following)
+    .map(|line| Ok::<Event, Infallible>(Event::default().data(line)));
+
+  Sse::new(events).keep_alive(KeepAlive::default()).into_response()
+}
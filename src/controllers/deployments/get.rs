@@ -6,6 +6,16 @@ use crate::{
   types::view::{get_deployment::GetDeployment, logs::Logs},
 };
 
+#[utoipa::path(
+  get,
+  path = "/api/v1/deployments/{id}",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  responses(
+    (status = 200, body = GetDeployment),
+    (status = 404, body = GetDeployment),
+  ),
+  tag = "deployments",
+)]
 pub async fn get(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
   let id_opt = queries.get("id").cloned();
 
@@ -0,0 +1,58 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{get_build::GetBuild, logs::Logs},
+};
+
+#[utoipa::path(
+  get,
+  path = "/api/v1/deployments/{id}/builds/{build_id}",
+  params(
+    ("id" = String, Path, description = "Id of the deployment"),
+    ("build_id" = String, Path, description = "Id of the build"),
+  ),
+  responses(
+    (status = 200, body = GetBuild),
+    (status = 404, body = GetBuild),
+  ),
+  tag = "deployments",
+)]
+pub async fn get_build(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let (Some(id), Some(build_id)) = (queries.get("id").cloned(), queries.get("build_id").cloned()) else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(GetBuild {
+        logs: Logs {
+          message: "deploymentId and buildId are required".to_string(),
+          errors: vec![],
+        },
+        build: None,
+      }),
+    );
+  };
+
+  match deployments::get_build(id, build_id).await {
+    Ok(build) => (
+      StatusCode::OK,
+      Json(GetBuild {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        build: Some(build),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(GetBuild {
+        logs: Logs {
+          message: "Failed to retrieve build".to_string(),
+          errors: vec![e.message],
+        },
+        build: None,
+      }),
+    ),
+  }
+}
@@ -1,33 +1,67 @@
 use axum::{
-  extract::Path,
+  extract::{Path, Query},
   http::StatusCode,
-  response::IntoResponse, Json,
+  response::{
+    sse::{Event, KeepAlive, Sse},
+    IntoResponse, Response,
+  },
+  Json,
 };
-use std::collections::HashMap;
+use futures::{stream, StreamExt};
+use std::{collections::HashMap, convert::Infallible};
 
 use crate::{
   business::services::deployments,
-  types::view::{get_deployment_logs::GetDeploymentLogs, logs::Logs},
+  controllers::parse_query,
+  types::{
+    other::container_logs::ContainerLogsOptions,
+    view::{get_deployment_logs::GetDeploymentLogs, logs::Logs},
+  },
 };
 
-
-pub async fn get_logs(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
-  let id_opt = queries.get("id").cloned();
+#[utoipa::path(
+  get,
+  path = "/api/v1/deployments/{id}/logs",
+  params(
+    ("id" = String, Path, description = "Id of the deployment"),
+    ("tail" = Option<String>, Query, description = "Number of lines from the end, or all"),
+    ("since" = Option<i64>, Query, description = "Unix timestamp of the oldest line"),
+    ("until" = Option<i64>, Query, description = "Unix timestamp of the newest line"),
+    ("timestamps" = Option<bool>, Query, description = "Prefix lines with their timestamp"),
+    ("follow" = Option<bool>, Query, description = "Stream new lines as Server-Sent Events"),
+  ),
+  responses(
+    (status = 200, body = GetDeploymentLogs),
+    (status = 400, body = GetDeploymentLogs),
+    (status = 404, body = GetDeploymentLogs),
+  ),
+  tag = "deployments",
+)]
+pub async fn get_logs(
+  Path(path): Path<HashMap<String, String>>,
+  Query(queries): Query<HashMap<String, String>>,
+) -> Response {
+  let id_opt = path.get("id").cloned();
 
   let Some(id) = id_opt else {
-    return (
-      StatusCode::BAD_REQUEST,
-      Json(GetDeploymentLogs {
-        logs: Logs {
-          message: "deploymentId is required".to_string(),
-          errors: vec![],
-        },
-        deployment_logs: None,
-      }),
-    );
+    return bad_request("deploymentId is required");
+  };
+
+  let options = match parse_options(&queries) {
+    Ok(options) => options,
+    Err(message) => return bad_request(&message),
+  };
+
+  let follow = match parse_query::<bool>(&queries, "follow") {
+    Ok(follow) => follow.unwrap_or(false),
+    Err(message) => return bad_request(&message),
   };
 
-  match deployments::get_logs(id).await {
+  if follow {
+    return follow_logs(id, options).await;
+  }
+
+  match deployments::get_logs(id, options).await {
     Ok(deployment_logs) => (
       StatusCode::OK,
       Json(GetDeploymentLogs {
@@ -37,16 +71,63 @@ pub async fn get_logs(Path(queries): Path<HashMap<String, String>>) -> impl Into
         },
         deployment_logs: Some(deployment_logs),
       }),
-    ),
-    Err(e) => (
-      e.status_code,
-      Json(GetDeploymentLogs {
-        logs: Logs {
-          message: "Failed to retrieve deployment logs".to_string(),
-          errors: vec![e.message],
-        },
-        deployment_logs: None,
-      }),
-    ),
+    )
+      .into_response(),
+    Err(e) => failure(e.status_code, e.message),
   }
 }
+
+// Streams the logs as Server-Sent Events, one event per line, named after the output it was written to
+async fn follow_logs(id: String, options: ContainerLogsOptions) -> Response {
+  let receiver = match deployments::follow_logs(id, options).await {
+    Ok(receiver) => receiver,
+    Err(e) => return failure(e.status_code, e.message),
+  };
+
+  let events = stream::unfold(receiver, |mut receiver| async move {
+    receiver.recv().await.map(|log| (log, receiver))
+  })
+  .map(|log| Ok::<Event, Infallible>(Event::default().event(log.source.to_string()).data(log.message)));
+
+  Sse::new(events).keep_alive(KeepAlive::default()).into_response()
+}
+
+fn parse_options(queries: &HashMap<String, String>) -> Result<ContainerLogsOptions, String> {
+  Ok(ContainerLogsOptions {
+    tail: match queries.get("tail").map(String::as_str) {
+      None | Some("all") => None,
+      Some(_) => parse_query(queries, "tail")?,
+    },
+    since: parse_query(queries, "since")?,
+    until: parse_query(queries, "until")?,
+    timestamps: parse_query(queries, "timestamps")?.unwrap_or(false),
+  })
+}
+
+fn bad_request(message: &str) -> Response {
+  (
+    StatusCode::BAD_REQUEST,
+    Json(GetDeploymentLogs {
+      logs: Logs {
+        message: message.to_string(),
+        errors: vec![],
+      },
+      deployment_logs: None,
+    }),
+  )
+    .into_response()
+}
+
+fn failure(status_code: StatusCode, error: String) -> Response {
+  (
+    status_code,
+    Json(GetDeploymentLogs {
+      logs: Logs {
+        message: "Failed to retrieve deployment logs".to_string(),
+        errors: vec![error],
+      },
+      deployment_logs: None,
+    }),
+  )
+    .into_response()
+}
@@ -6,6 +6,16 @@ use crate::{
   types::view::{get_deployments::GetDeployments, logs::Logs},
 };
 
+#[utoipa::path(
+  get,
+  path = "/api/v1/deployments",
+  params(
+    ("repoUrl" = Option<String>, Query, description = "Only deployments of this repository"),
+    ("branch" = Option<String>, Query, description = "Only deployments of this branch"),
+  ),
+  responses((status = 200, body = GetDeployments)),
+  tag = "deployments",
+)]
 pub async fn list(Query(queries): Query<HashMap<String, String>>) -> impl IntoResponse {
   let repo_url = queries.get("repoUrl").cloned();
   let branch = queries.get("branch").cloned();
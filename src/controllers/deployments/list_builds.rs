@@ -0,0 +1,57 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{get_builds::GetBuilds, logs::Logs},
+};
+
+#[utoipa::path(
+  get,
+  path = "/api/v1/deployments/{id}/builds",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  responses(
+    (status = 200, body = GetBuilds),
+    (status = 404, body = GetBuilds),
+  ),
+  tag = "deployments",
+)]
+pub async fn list_builds(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let id_opt = queries.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(GetBuilds {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+        builds: vec![],
+      }),
+    );
+  };
+
+  match deployments::list_builds(id).await {
+    Ok(builds) => (
+      StatusCode::OK,
+      Json(GetBuilds {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        builds,
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(GetBuilds {
+        logs: Logs {
+          message: "Failed to retrieve builds".to_string(),
+          errors: vec![e.message],
+        },
+        builds: vec![],
+      }),
+    ),
+  }
+}
@@ -0,0 +1,57 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{get_environment::GetEnvironment, logs::Logs},
+};
+
+#[utoipa::path(
+  get,
+  path = "/api/v1/deployments/{id}/env",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  responses(
+    (status = 200, body = GetEnvironment),
+    (status = 404, body = GetEnvironment),
+  ),
+  tag = "deployments",
+)]
+pub async fn list_environment(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let id_opt = queries.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(GetEnvironment {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+        environment: vec![],
+      }),
+    );
+  };
+
+  match deployments::list_environment(id).await {
+    Ok(environment) => (
+      StatusCode::OK,
+      Json(GetEnvironment {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        environment: environment.into_iter().map(Into::into).collect(),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(GetEnvironment {
+        logs: Logs {
+          message: "Failed to retrieve environment variables".to_string(),
+          errors: vec![e.message],
+        },
+        environment: vec![],
+      }),
+    ),
+  }
+}
@@ -1,14 +1,42 @@
 mod create;
 mod delete;
+mod delete_environment_variable;
+mod extend;
+mod follow_build_logs;
 mod get;
+mod get_build;
 mod get_logs;
 mod list;
+mod list_builds;
+mod list_environment;
+mod redeploy;
+mod restart;
+mod rollback;
+mod set_build_options;
+mod set_environment_variable;
+mod set_resource_limits;
+mod start;
+mod stop;
 
 pub use create::*;
 pub use delete::*;
+pub use delete_environment_variable::*;
+pub use extend::*;
+pub use follow_build_logs::*;
 pub use get::*;
+pub use get_build::*;
 pub use get_logs::*;
 pub use list::*;
+pub use list_builds::*;
+pub use list_environment::*;
+pub use redeploy::*;
+pub use restart::*;
+pub use rollback::*;
+pub use set_build_options::*;
+pub use set_environment_variable::*;
+pub use set_resource_limits::*;
+pub use start::*;
+pub use stop::*;
 
 
 
@@ -0,0 +1,60 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{logs::Logs, redeploy_deployment::RedeployDeployment},
+};
+
+#[utoipa::path(
+  post,
+  path = "/api/v1/deployments/{id}/redeploy",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  responses(
+    (status = 200, description = "Redeployed", body = RedeployDeployment),
+    (status = 400, description = "Invalid id, missing branch or invalid .dockerignore", body = RedeployDeployment),
+    (status = 404, description = "Deployment not found", body = RedeployDeployment),
+    (status = 409, description = "Being deployed or deleted", body = RedeployDeployment),
+    (status = 500, description = "Failed to build or start the deployment", body = RedeployDeployment),
+  ),
+  tag = "deployments",
+)]
+pub async fn redeploy(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let id_opt = queries.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(RedeployDeployment {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+        id: None,
+      }),
+    );
+  };
+
+  match deployments::redeploy(id).await {
+    Ok(deployment_id) => (
+      StatusCode::OK,
+      Json(RedeployDeployment {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        id: Some(deployment_id),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(RedeployDeployment {
+        logs: Logs {
+          message: "Failed to redeploy deployment".to_string(),
+          errors: vec![e.message],
+        },
+        id: None,
+      }),
+    ),
+  }
+}
@@ -0,0 +1,68 @@
+use axum::{
+  extract::{Path, Query},
+  http::StatusCode,
+  response::IntoResponse,
+  Json,
+};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{logs::Logs, rollback_deployment::RollbackDeployment},
+};
+
+#[utoipa::path(
+  post,
+  path = "/api/v1/deployments/{id}/rollback",
+  params(
+    ("id" = String, Path, description = "Id of the deployment"),
+    ("to" = String, Query, description = "Id of the build whose image to roll back to"),
+  ),
+  responses(
+    (status = 200, body = RollbackDeployment),
+    (status = 400, body = RollbackDeployment),
+    (status = 404, body = RollbackDeployment),
+    (status = 409, body = RollbackDeployment),
+  ),
+  tag = "deployments",
+)]
+pub async fn rollback(
+  Path(path): Path<HashMap<String, String>>,
+  Query(queries): Query<HashMap<String, String>>,
+) -> impl IntoResponse {
+  let (Some(id), Some(build_id)) = (path.get("id").cloned(), queries.get("to").cloned()) else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(RollbackDeployment {
+        logs: Logs {
+          message: "deploymentId and to (a build id) are required".to_string(),
+          errors: vec![],
+        },
+        id: None,
+      }),
+    );
+  };
+
+  match deployments::rollback(id, build_id).await {
+    Ok(deployment_id) => (
+      StatusCode::OK,
+      Json(RollbackDeployment {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        id: Some(deployment_id),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(RollbackDeployment {
+        logs: Logs {
+          message: "Failed to roll back deployment".to_string(),
+          errors: vec![e.message],
+        },
+        id: None,
+      }),
+    ),
+  }
+}
@@ -0,0 +1,80 @@
+use axum::{
+  extract::{rejection::JsonRejection, Path},
+  http::StatusCode,
+  response::IntoResponse,
+  Json,
+};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::{
+    model::deployment::BuildOptions,
+    view::{logs::Logs, build_options_request::BuildOptionsRequest, update_build_options::UpdateBuildOptions},
+  },
+};
+
+#[utoipa::path(
+  put,
+  path = "/api/v1/deployments/{id}/build-options",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  request_body = BuildOptionsRequest,
+  responses(
+    (status = 200, body = UpdateBuildOptions),
+    (status = 400, body = UpdateBuildOptions),
+    (status = 404, body = UpdateBuildOptions),
+  ),
+  tag = "deployments",
+)]
+pub async fn set_build_options(
+  Path(queries): Path<HashMap<String, String>>,
+  body: Result<Json<BuildOptionsRequest>, JsonRejection>,
+) -> impl IntoResponse {
+  let id_opt = queries.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(UpdateBuildOptions {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+      }),
+    );
+  };
+
+  let build_options = match body {
+    Ok(Json(build_options)) => BuildOptions::from(build_options),
+    Err(e) => return (
+      StatusCode::BAD_REQUEST,
+      Json(UpdateBuildOptions {
+        logs: Logs {
+          message: "Invalid request body".to_string(),
+          errors: vec![e.body_text()],
+        },
+      }),
+    ),
+  };
+
+  match deployments::set_build_options(id, build_options).await {
+    Ok(()) => (
+      StatusCode::OK,
+      Json(UpdateBuildOptions {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(UpdateBuildOptions {
+        logs: Logs {
+          message: "Failed to set build options".to_string(),
+          errors: vec![e.message],
+        },
+      }),
+    ),
+  }
+}
@@ -0,0 +1,80 @@
+use axum::{
+  extract::{rejection::JsonRejection, Path},
+  http::StatusCode,
+  response::IntoResponse,
+  Json,
+};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{
+    logs::Logs, set_environment_variable::SetEnvironmentVariable, update_environment::UpdateEnvironment,
+  },
+};
+
+#[utoipa::path(
+  put,
+  path = "/api/v1/deployments/{id}/env/{name}",
+  params(
+    ("id" = String, Path, description = "Id of the deployment"),
+    ("name" = String, Path, description = "Name of the variable"),
+  ),
+  request_body = SetEnvironmentVariable,
+  responses(
+    (status = 200, body = UpdateEnvironment),
+    (status = 400, body = UpdateEnvironment),
+    (status = 404, body = UpdateEnvironment),
+  ),
+  tag = "deployments",
+)]
+pub async fn set_environment_variable(
+  Path(queries): Path<HashMap<String, String>>,
+  body: Result<Json<SetEnvironmentVariable>, JsonRejection>,
+) -> impl IntoResponse {
+  let (Some(id), Some(name)) = (queries.get("id").cloned(), queries.get("name").cloned()) else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(UpdateEnvironment {
+        logs: Logs {
+          message: "deploymentId and name are required".to_string(),
+          errors: vec![],
+        },
+      }),
+    );
+  };
+
+  let body = match body {
+    Ok(Json(body)) => body,
+    Err(e) => return (
+      StatusCode::BAD_REQUEST,
+      Json(UpdateEnvironment {
+        logs: Logs {
+          message: "Invalid request body".to_string(),
+          errors: vec![e.body_text()],
+        },
+      }),
+    ),
+  };
+
+  match deployments::set_environment_variable(id, name, body.value, body.secret).await {
+    Ok(()) => (
+      StatusCode::OK,
+      Json(UpdateEnvironment {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(UpdateEnvironment {
+        logs: Logs {
+          message: "Failed to set environment variable".to_string(),
+          errors: vec![e.message],
+        },
+      }),
+    ),
+  }
+}
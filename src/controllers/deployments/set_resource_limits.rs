@@ -0,0 +1,80 @@
+use axum::{
+  extract::{rejection::JsonRejection, Path},
+  http::StatusCode,
+  response::IntoResponse,
+  Json,
+};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::{
+    model::deployment::ResourceLimits,
+    view::{logs::Logs, resource_limits_request::ResourceLimitsRequest, update_resource_limits::UpdateResourceLimits},
+  },
+};
+
+#[utoipa::path(
+  put,
+  path = "/api/v1/deployments/{id}/resources",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  request_body = ResourceLimitsRequest,
+  responses(
+    (status = 200, body = UpdateResourceLimits),
+    (status = 400, body = UpdateResourceLimits),
+    (status = 404, body = UpdateResourceLimits),
+  ),
+  tag = "deployments",
+)]
+pub async fn set_resource_limits(
+  Path(queries): Path<HashMap<String, String>>,
+  body: Result<Json<ResourceLimitsRequest>, JsonRejection>,
+) -> impl IntoResponse {
+  let id_opt = queries.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(UpdateResourceLimits {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+      }),
+    );
+  };
+
+  let resource_limits = match body {
+    Ok(Json(resource_limits)) => ResourceLimits::from(resource_limits),
+    Err(e) => return (
+      StatusCode::BAD_REQUEST,
+      Json(UpdateResourceLimits {
+        logs: Logs {
+          message: "Invalid request body".to_string(),
+          errors: vec![e.body_text()],
+        },
+      }),
+    ),
+  };
+
+  match deployments::set_resource_limits(id, resource_limits).await {
+    Ok(()) => (
+      StatusCode::OK,
+      Json(UpdateResourceLimits {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(UpdateResourceLimits {
+        logs: Logs {
+          message: "Failed to set resource limits".to_string(),
+          errors: vec![e.message],
+        },
+      }),
+    ),
+  }
+}
@@ -0,0 +1,57 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::deployments,
+  types::view::{change_deployment_state::ChangeDeploymentState, logs::Logs},
+};
+
+#[utoipa::path(
+  post,
+  path = "/api/v1/deployments/{id}/stop",
+  params(("id" = String, Path, description = "Id of the deployment")),
+  responses(
+    (status = 200, body = ChangeDeploymentState),
+    (status = 409, description = "Neither deployed nor stopped", body = ChangeDeploymentState),
+  ),
+  tag = "deployments",
+)]
+pub async fn stop(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let id_opt = queries.get("id").cloned();
+
+  let Some(id) = id_opt else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(ChangeDeploymentState {
+        logs: Logs {
+          message: "deploymentId is required".to_string(),
+          errors: vec![],
+        },
+        state: None,
+      }),
+    );
+  };
+
+  match deployments::stop(id).await {
+    Ok(state) => (
+      StatusCode::OK,
+      Json(ChangeDeploymentState {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        state: Some(state),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(ChangeDeploymentState {
+        logs: Logs {
+          message: "Failed to stop deployment".to_string(),
+          errors: vec![e.message],
+        },
+        state: None,
+      }),
+    ),
+  }
+}
@@ -0,0 +1,58 @@
+use axum::{extract::rejection::JsonRejection, http::StatusCode, response::IntoResponse, Json};
+
+use crate::{
+  business::services::git_credentials,
+  types::view::{
+    create_git_credential::CreateGitCredential, create_git_credential_request::CreateGitCredentialRequest, logs::Logs,
+  },
+};
+
+#[utoipa::path(
+  post,
+  path = "/api/v1/git-credentials",
+  request_body = CreateGitCredentialRequest,
+  responses(
+    (status = 201, description = "Credential created, its secret is never sent back", body = CreateGitCredential),
+    (status = 400, description = "Invalid credential, or SECRETS_KEY is not set", body = CreateGitCredential),
+    (status = 403, description = "Requires the admin scope"),
+  ),
+  tag = "git-credentials",
+)]
+pub async fn create(body: Result<Json<CreateGitCredentialRequest>, JsonRejection>) -> impl IntoResponse {
+  let body = match body {
+    Ok(Json(body)) => body,
+    Err(e) => return (
+      StatusCode::BAD_REQUEST,
+      Json(CreateGitCredential {
+        logs: Logs {
+          message: "Invalid request body".to_string(),
+          errors: vec![e.body_text()],
+        },
+        git_credential: None,
+      }),
+    ),
+  };
+
+  match git_credentials::create(body).await {
+    Ok(git_credential) => (
+      StatusCode::CREATED,
+      Json(CreateGitCredential {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        git_credential: Some(git_credential.into()),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(CreateGitCredential {
+        logs: Logs {
+          message: "Failed to create git credential".to_string(),
+          errors: vec![e.message],
+        },
+        git_credential: None,
+      }),
+    ),
+  }
+}
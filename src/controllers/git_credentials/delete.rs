@@ -0,0 +1,53 @@
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Json};
+use std::collections::HashMap;
+
+use crate::{
+  business::services::git_credentials,
+  types::view::{delete_git_credential::DeleteGitCredential, logs::Logs},
+};
+
+#[utoipa::path(
+  delete,
+  path = "/api/v1/git-credentials/{id}",
+  params(("id" = String, Path, description = "Id of the git credential")),
+  responses(
+    (status = 200, body = DeleteGitCredential),
+    (status = 403, description = "Requires the admin scope"),
+    (status = 404, body = DeleteGitCredential),
+  ),
+  tag = "git-credentials",
+)]
+pub async fn delete(Path(queries): Path<HashMap<String, String>>) -> impl IntoResponse {
+  let Some(id) = queries.get("id").cloned() else {
+    return (
+      StatusCode::BAD_REQUEST,
+      Json(DeleteGitCredential {
+        logs: Logs {
+          message: "gitCredentialId is required".to_string(),
+          errors: vec![],
+        },
+      }),
+    );
+  };
+
+  match git_credentials::delete(id).await {
+    Ok(()) => (
+      StatusCode::OK,
+      Json(DeleteGitCredential {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(DeleteGitCredential {
+        logs: Logs {
+          message: "Failed to delete git credential".to_string(),
+          errors: vec![e.message],
+        },
+      }),
+    ),
+  }
+}
@@ -0,0 +1,40 @@
+use axum::{http::StatusCode, response::IntoResponse, Json};
+
+use crate::{
+  business::services::git_credentials,
+  types::view::{get_git_credentials::GetGitCredentials, logs::Logs},
+};
+
+#[utoipa::path(
+  get,
+  path = "/api/v1/git-credentials",
+  responses(
+    (status = 200, body = GetGitCredentials),
+    (status = 403, description = "Requires the admin scope"),
+  ),
+  tag = "git-credentials",
+)]
+pub async fn list() -> impl IntoResponse {
+  match git_credentials::list().await {
+    Ok(list) => (
+      StatusCode::OK,
+      Json(GetGitCredentials {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        git_credentials: list.into_iter().map(Into::into).collect(),
+      }),
+    ),
+    Err(e) => (
+      e.status_code,
+      Json(GetGitCredentials {
+        logs: Logs {
+          message: "Failed to retrieve git credentials".to_string(),
+          errors: vec![e.message],
+        },
+        git_credentials: vec![],
+      }),
+    ),
+  }
+}
@@ -1,11 +1,21 @@
+pub mod api_keys;
+pub mod audit;
+mod auditing;
+mod authorization;
 pub mod deployments;
+pub mod git_credentials;
+pub mod openapi;
+pub mod webhooks;
 
 use axum::{
-  extract::Request, http::StatusCode, middleware::{self, Next}, response::{IntoResponse, Json, Response}, routing::{delete, get, post}, Router
+  middleware, response::{IntoResponse, Json}, routing::{delete, get, post, put}, Router
 };
 use serde::Serialize;
+use std::{collections::HashMap, str::FromStr};
 
-use crate::{configs::environment::API_KEY, types::view::logs::Logs};
+use auditing::audit_middleware;
+use authorization::{authorization_middleware, require_deploy_scope, require_scope};
+use crate::types::{model::api_key::ApiKeyScope, view::logs::Logs};
 
 pub trait ConfigureRoutes {
   fn configure_routes(self) -> Self;
@@ -16,16 +26,74 @@ impl ConfigureRoutes for Router {
       "/api/v1",
       Self::new()
         .route("/status", get(status))
+        .route("/openapi.json", get(openapi::openapi))
         .nest(
           "/deployments",
           Self::new()
+          // Checks the scope of the requested mode itself
           .route("/", post(deployments::create))
-          .route("/", get(deployments::list))
-          .route("/:id", get(deployments::get))
-          .route("/:id", delete(deployments::delete))
-          .route("/:id/logs", get(deployments::get_logs))
+          .merge(
+            Self::new()
+            .route("/", get(deployments::list))
+            .route("/:id", get(deployments::get))
+            .route("/:id/logs", get(deployments::get_logs))
+            .route("/:id/build-logs", get(deployments::follow_build_logs))
+            .route("/:id/builds", get(deployments::list_builds))
+            .route("/:id/builds/:build_id", get(deployments::get_build))
+            .route("/:id/env", get(deployments::list_environment))
+            .route_layer(middleware::from_fn_with_state(ApiKeyScope::Read, require_scope)),
+          )
+          .merge(
+            Self::new()
+            .route("/:id/redeploy", post(deployments::redeploy))
+            .route("/:id/rollback", post(deployments::rollback))
+            .route("/:id/stop", post(deployments::stop))
+            .route("/:id/start", post(deployments::start))
+            .route("/:id/restart", post(deployments::restart))
+            .route("/:id/extend", post(deployments::extend))
+            .route("/:id/build-options", put(deployments::set_build_options))
+            .route("/:id/resources", put(deployments::set_resource_limits))
+            .route("/:id/env/:name", put(deployments::set_environment_variable))
+            .route("/:id/env/:name", delete(deployments::delete_environment_variable))
+            .route_layer(middleware::from_fn(require_deploy_scope)),
+          )
+          .merge(
+            Self::new()
+            .route("/:id", delete(deployments::delete))
+            .route_layer(middleware::from_fn_with_state(ApiKeyScope::Delete, require_scope)),
+          )
+          .layer(middleware::from_fn(audit_middleware))
           .layer(middleware::from_fn(authorization_middleware)),
-        ),
+        )
+        .nest(
+          "/api-keys",
+          Self::new()
+          .route("/", post(api_keys::create))
+          .route("/", get(api_keys::list))
+          .route("/:id", delete(api_keys::revoke))
+          .route_layer(middleware::from_fn_with_state(ApiKeyScope::Admin, require_scope))
+          .layer(middleware::from_fn(audit_middleware))
+          .layer(middleware::from_fn(authorization_middleware)),
+        )
+        .nest(
+          "/git-credentials",
+          Self::new()
+          .route("/", post(git_credentials::create))
+          .route("/", get(git_credentials::list))
+          .route("/:id", delete(git_credentials::delete))
+          .route_layer(middleware::from_fn_with_state(ApiKeyScope::Admin, require_scope))
+          .layer(middleware::from_fn(audit_middleware))
+          .layer(middleware::from_fn(authorization_middleware)),
+        )
+        .nest(
+          "/audit",
+          Self::new()
+          .route("/", get(audit::list))
+          .route_layer(middleware::from_fn_with_state(ApiKeyScope::Admin, require_scope))
+          .layer(middleware::from_fn(authorization_middleware)),
+        )
+        // Authenticated by the payload signature instead of the API key
+        .route("/webhooks/push", post(webhooks::push).layer(middleware::from_fn(audit_middleware))),
     )
   }
 }
@@ -39,24 +107,12 @@ async fn status() -> impl IntoResponse {
   })
 }
 
-async fn authorization_middleware(
-  request: Request,
-  next: Next,
-) -> Response {
-  let api_key = request.headers().get("X-Api-Key");
-  #[allow(clippy::unwrap_used)] // Should never fail
-  if api_key.is_none() || api_key.unwrap() != &*API_KEY {
-    let mut response = Json(BasicResponse {
-      logs: Logs {
-        message: "Unauthorized".to_string(),
-        errors: vec!["Invalid API Key".to_string()]
-      }
-    }).into_response();
-    *response.status_mut() = StatusCode::UNAUTHORIZED;
-    response
-  } else {
-    next.run(request).await
-  }
+// Shared by the handlers that take their options as query parameters
+pub fn parse_query<T: FromStr>(queries: &HashMap<String, String>, name: &str) -> Result<Option<T>, String> {
+  queries
+    .get(name)
+    .map(|value| value.parse::<T>().map_err(|_| format!("Invalid value '{value}' for {name}")))
+    .transpose()
 }
 
 #[derive(Serialize)]
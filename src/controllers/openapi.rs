@@ -0,0 +1,117 @@
+// The OpenApi derive expands to for_each calls
+#![allow(clippy::needless_for_each)]
+
+use axum::{response::IntoResponse, Json};
+use utoipa::{
+  openapi::security::{ApiKey, ApiKeyValue, SecurityScheme},
+  Modify, OpenApi,
+};
+
+use crate::{
+  controllers::{api_keys, audit, deployments, git_credentials, webhooks},
+  types::{
+    model::{
+      api_key::ApiKeyScope,
+      audit_log::{AuditLog, AuditOutcome, AuditParams},
+      build::{Build, BuildOutcome, BuildStep},
+      deployment::{BuildOptions, Commit, Deployment, DeploymentState, Mode, ResourceLimits},
+      git_credential::GitCredentialKind,
+    },
+    other::{
+      container_logs::{ContainerLog, LogSource},
+      new_deployment::NewEnvironmentVariable,
+    },
+    view::{
+      build_options_request::BuildOptionsRequest, change_deployment_state::ChangeDeploymentState, create_api_key::CreateApiKey,
+      create_api_key_request::CreateApiKeyRequest, create_deployment::CreateDeployment,
+      create_deployment_request::CreateDeploymentRequest, create_git_credential::CreateGitCredential,
+      create_git_credential_request::CreateGitCredentialRequest, delete_deployment::DeleteDeployment,
+      delete_git_credential::DeleteGitCredential,
+      extend_deployment::ExtendDeployment, field_error::FieldError, follow_build_logs::FollowBuildLogs,
+      get_api_keys::{ApiKeyInfo, GetApiKeys}, get_audit_logs::GetAuditLogs, get_build::GetBuild, get_builds::GetBuilds, get_deployment::GetDeployment,
+      get_deployment_logs::GetDeploymentLogs, get_deployments::GetDeployments,
+      get_environment::{EnvironmentVariableInfo, GetEnvironment},
+      get_git_credentials::{GetGitCredentials, GitCredentialInfo}, logs::Logs,
+      push_event::{PushEvent, PushRepository}, push_webhook::PushWebhook,
+      redeploy_deployment::RedeployDeployment, resource_limits_request::ResourceLimitsRequest, revoke_api_key::RevokeApiKey, rollback_deployment::RollbackDeployment, set_environment_variable::SetEnvironmentVariable,
+      update_build_options::UpdateBuildOptions, update_environment::UpdateEnvironment,
+      update_resource_limits::UpdateResourceLimits,
+    },
+  },
+};
+
+// Generated from the handlers and the views, so it follows the API as it changes
+#[derive(OpenApi)]
+#[openapi(
+  info(title = "Voyager API"),
+  paths(
+    deployments::create,
+    deployments::list,
+    deployments::get,
+    deployments::delete,
+    deployments::get_logs,
+    deployments::follow_build_logs,
+    deployments::list_builds,
+    deployments::get_build,
+    deployments::redeploy,
+    deployments::rollback,
+    deployments::stop,
+    deployments::start,
+    deployments::restart,
+    deployments::extend,
+    deployments::set_build_options,
+    deployments::set_resource_limits,
+    deployments::list_environment,
+    deployments::set_environment_variable,
+    deployments::delete_environment_variable,
+    api_keys::create,
+    api_keys::list,
+    api_keys::revoke,
+    git_credentials::create,
+    git_credentials::list,
+    git_credentials::delete,
+    audit::list,
+    webhooks::push,
+  ),
+  components(schemas(
+    Logs, FieldError,
+    Deployment, Commit, Mode, DeploymentState, BuildOptions, ResourceLimits,
+    Build, BuildOutcome, BuildStep,
+    ContainerLog, LogSource, NewEnvironmentVariable, EnvironmentVariableInfo,
+    CreateDeploymentRequest, BuildOptionsRequest, ResourceLimitsRequest, CreateDeployment, GetDeployments, GetDeployment, DeleteDeployment,
+    GetDeploymentLogs, FollowBuildLogs, GetBuilds, GetBuild, RedeployDeployment, RollbackDeployment,
+    ChangeDeploymentState, ExtendDeployment, UpdateBuildOptions, UpdateResourceLimits,
+    GetEnvironment, SetEnvironmentVariable, UpdateEnvironment,
+    AuditLog, AuditParams, AuditOutcome, GetAuditLogs,
+    ApiKeyScope, ApiKeyInfo, CreateApiKeyRequest, CreateApiKey, GetApiKeys, RevokeApiKey,
+    GitCredentialKind, GitCredentialInfo, CreateGitCredentialRequest, CreateGitCredential, GetGitCredentials,
+    DeleteGitCredential,
+    PushEvent, PushRepository, PushWebhook,
+  )),
+  modifiers(&ApiKeyAddon),
+  security(("api_key" = [])),
+  tags(
+    (name = "deployments", description = "Deployments and their builds, logs and environment"),
+    (name = "api-keys", description = "Managing API keys, which requires the admin scope"),
+    (name = "git-credentials", description = "Credentials to clone repositories with, which requires the admin scope"),
+    (name = "audit", description = "Every call that is not a GET, which requires the admin scope to read"),
+    (name = "webhooks", description = "Authenticated by the payload signature instead of the API key"),
+  ),
+)]
+pub struct ApiDoc;
+
+struct ApiKeyAddon;
+impl Modify for ApiKeyAddon {
+  fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+    if let Some(components) = openapi.components.as_mut() {
+      components.add_security_scheme(
+        "api_key",
+        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Api-Key"))),
+      );
+    }
+  }
+}
+
+pub async fn openapi() -> impl IntoResponse {
+  Json(ApiDoc::openapi())
+}
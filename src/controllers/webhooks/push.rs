@@ -0,0 +1,93 @@
+use axum::{
+  body::Bytes,
+  http::{HeaderMap, StatusCode},
+  response::IntoResponse,
+  Json,
+};
+
+use crate::{
+  business::services::webhooks,
+  configs::environment::WEBHOOK_SECRET,
+  types::view::{logs::Logs, push_event::PushEvent, push_webhook::PushWebhook},
+  utils::verify_signature,
+};
+
+#[utoipa::path(
+  post,
+  path = "/api/v1/webhooks/push",
+  params(
+    ("X-Hub-Signature-256" = String, Header, description = "HMAC-SHA256 of the payload, keyed with the webhook secret"),
+  ),
+  request_body = PushEvent,
+  responses(
+    (status = 202, description = "Redeploys queued", body = PushWebhook),
+    (status = 200, description = "Ping or non-push event", body = PushWebhook),
+    (status = 400, body = PushWebhook),
+    (status = 401, description = "Invalid signature", body = PushWebhook),
+    (status = 404, description = "WEBHOOK_SECRET is not set", body = PushWebhook),
+  ),
+  security(()),
+  tag = "webhooks",
+)]
+pub async fn push(headers: HeaderMap, body: Bytes) -> impl IntoResponse {
+  let header = |name: &str| headers.get(name).and_then(|h| h.to_str().ok());
+  let response = |status: StatusCode, message: &str, errors: Vec<String>| {
+    (
+      status,
+      Json(PushWebhook {
+        logs: Logs {
+          message: message.to_string(),
+          errors,
+        },
+        deployments: vec![],
+      }),
+    )
+  };
+
+  let Some(secret) = WEBHOOK_SECRET.as_ref() else {
+    return response(StatusCode::NOT_FOUND, "Webhooks are not configured", vec![]);
+  };
+
+  // GitHub prefixes the digest with the algorithm, Gitea sends the bare digest
+  let signature = header("X-Hub-Signature-256")
+    .and_then(|s| s.strip_prefix("sha256="))
+    .or_else(|| header("X-Gitea-Signature"));
+  if !signature.is_some_and(|s| verify_signature(secret, &body, s)) {
+    return response(
+      StatusCode::UNAUTHORIZED,
+      "Unauthorized",
+      vec!["Invalid webhook signature".to_string()],
+    );
+  }
+
+  match header("X-GitHub-Event").or_else(|| header("X-Gitea-Event")) {
+    Some("push") => {},
+    Some("ping") => return response(StatusCode::OK, "Pong!", vec![]),
+    _ => return response(StatusCode::OK, "Ignoring non-push event", vec![]),
+  }
+
+  let push_event = match serde_json::from_slice::<PushEvent>(&body) {
+    Ok(push_event) => push_event,
+    Err(e) => {
+      return response(
+        StatusCode::BAD_REQUEST,
+        "Invalid push event payload",
+        vec![e.to_string()],
+      )
+    },
+  };
+
+  match webhooks::push(push_event).await {
+    Ok(deployments) => (
+      StatusCode::ACCEPTED,
+      Json(PushWebhook {
+        logs: Logs {
+          message: "Success!".to_string(),
+          errors: vec![],
+        },
+        deployments,
+      }),
+    ),
+    Err(e) => response(e.status_code, "Failed to handle push event", vec![e.message]),
+  }
+}
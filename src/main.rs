@@ -21,7 +21,8 @@ use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::Layer;
 
-use crate::configs::environment::{HOSTNAME, LOG_DIRECTORY, PORT, STDOUT_LOG_SEVERITY};
+use crate::business::services::{background, SERVICES_RUNTIME};
+use crate::configs::environment::{DOMAINS, HOSTNAME, LOG_DIRECTORY, PORT, STDOUT_LOG_SEVERITY};
 use crate::controllers::ConfigureRoutes;
 use crate::utils::ExpectError;
 
@@ -44,6 +45,10 @@ async fn main() {
   // Logging - The variables are needed for the lifetime of the program
   let (_log_guard_0, _log_guard_1) = init_logging().expect_error(|e| format!("Failed to initialize logging: {e}"));
 
+  // Configuration that every deployment depends on is checked upfront, rather than by the first request
+  assert!(!DOMAINS.is_empty(), "DOMAINS must list at least one domain");
+  utils::check_secrets_key();
+
   // Defining sockets
   let sock_host = HOSTNAME
     .parse::<Ipv4Addr>()
@@ -61,6 +66,11 @@ async fn main() {
 
   let app = Router::new().configure_routes();
 
+  // Background tasks
+  background::recover_interrupted().await;
+  SERVICES_RUNTIME.spawn(background::health_monitor());
+  SERVICES_RUNTIME.spawn(background::reaper());
+
   let listener = tokio::net::TcpListener::bind(sock_addr)
     .await
     .expect_error(|e| format!("Failed to bind to socket! Error: {e}"));
@@ -2,7 +2,7 @@ use axum::http::StatusCode;
 use serde_json::Value;
 use tracing::{event, Level};
 
-use crate::configs::environment::{CLOUDFLARE_ZONE, DEVELOPMENT};
+use crate::configs::environment::CLOUDFLARE_ZONE;
 use crate::modules::cloudflare::types::add_dns_record::{FailureResponse, OkResponse};
 use crate::modules::cloudflare::types::dns_record::DnsRecord;
 use crate::modules::cloudflare::CLOUDFLARE_CLIENT;
@@ -14,10 +14,6 @@ use crate::utils::http_client::http_error::HttpError;
 use crate::utils::Error;
 
 pub async fn add_dns_record(host: &str, ip: &str, mode: &Mode) -> Result<String, VoyagerError> {
-  if *DEVELOPMENT {
-    return Ok("devDnsRecord".to_string());
-  }
-
   event!(
     Level::INFO,
     "Adding DNS record to Cloudflare for host: {}, ip: {}, mode: {:?}",
@@ -2,7 +2,7 @@ use axum::http::StatusCode;
 use serde_json::Value;
 use tracing::{event, Level};
 
-use crate::configs::environment::{CLOUDFLARE_ZONE, DEVELOPMENT};
+use crate::configs::environment::CLOUDFLARE_ZONE;
 use crate::modules::cloudflare::types::delete_dns_record::{FailureResponse, OkResponse};
 use crate::modules::cloudflare::CLOUDFLARE_CLIENT;
 use crate::types::other::voyager_error::VoyagerError;
@@ -12,10 +12,6 @@ use crate::utils::http_client::http_error::HttpError;
 use crate::utils::Error;
 
 pub async fn delete_dns_record(dns_record: &str) -> Result<(), VoyagerError> {
-  if *DEVELOPMENT {
-    return Ok(());
-  }
-
   event!(
     Level::INFO,
     "Removing DNS record from Cloudflare: {}",
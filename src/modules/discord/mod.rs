@@ -1,3 +0,0 @@
-mod send_deployment_message;
-
-pub use send_deployment_message::*;
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use super::DnsProvider;
+use crate::{
+  modules::cloudflare,
+  types::{model::deployment::Mode, other::voyager_error::VoyagerError},
+};
+
+pub struct CloudflareDnsProvider;
+
+#[async_trait]
+impl DnsProvider for CloudflareDnsProvider {
+  async fn add_record(&self, host: &str, ip: &str, mode: Mode) -> Result<Option<String>, VoyagerError> {
+    cloudflare::add_dns_record(host, ip, &mode).await.map(Some)
+  }
+
+  async fn delete_record(&self, record_id: &str) -> Result<(), VoyagerError> {
+    cloudflare::delete_dns_record(record_id).await
+  }
+}
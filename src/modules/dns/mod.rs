@@ -0,0 +1,31 @@
+mod cloudflare_dns_provider;
+mod noop_dns_provider;
+mod rfc2136_dns_provider;
+
+pub use cloudflare_dns_provider::*;
+pub use noop_dns_provider::*;
+pub use rfc2136_dns_provider::*;
+
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+
+use crate::{
+  configs::environment::DNS_PROVIDER as DNS_PROVIDER_NAME,
+  types::{model::deployment::Mode, other::voyager_error::VoyagerError},
+};
+
+#[async_trait]
+pub trait DnsProvider: Sync + Send {
+  // Points host to ip. Returns the id to delete the record with, if a record was created
+  async fn add_record(&self, host: &str, ip: &str, mode: Mode) -> Result<Option<String>, VoyagerError>;
+  async fn delete_record(&self, record_id: &str) -> Result<(), VoyagerError>;
+}
+
+lazy_static! {
+  pub static ref DNS_PROVIDER: Box<dyn DnsProvider> = match DNS_PROVIDER_NAME.as_str() {
+    "cloudflare" => Box::new(CloudflareDnsProvider),
+    "rfc2136" => Box::new(Rfc2136DnsProvider::from_environment()),
+    "none" => Box::new(NoopDnsProvider),
+    name => panic!("Unknown DNS_PROVIDER '{name}', must be one of cloudflare, rfc2136 or none"),
+  };
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+use super::DnsProvider;
+use crate::types::{model::deployment::Mode, other::voyager_error::VoyagerError};
+
+// For when records are managed elsewhere, e.g. with a wildcard record, or not needed as in development
+pub struct NoopDnsProvider;
+
+#[async_trait]
+impl DnsProvider for NoopDnsProvider {
+  async fn add_record(&self, host: &str, ip: &str, mode: Mode) -> Result<Option<String>, VoyagerError> {
+    event!(Level::DEBUG, "Not adding DNS record for host: {host}, ip: {ip}, mode: {mode}");
+    Ok(None)
+  }
+
+  async fn delete_record(&self, record_id: &str) -> Result<(), VoyagerError> {
+    event!(Level::DEBUG, "Not removing DNS record: {record_id}");
+    Ok(())
+  }
+}
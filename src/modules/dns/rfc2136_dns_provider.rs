@@ -0,0 +1,237 @@
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hickory_proto::{
+  op::{update_message, Message, ResponseCode, UpdateMessage},
+  rr::{
+    dnssec::{rdata::tsig::TsigAlgorithm, tsig::TSigner},
+    rdata::{A, AAAA},
+    Name, RData, Record, RecordType,
+  },
+};
+use std::{net::{IpAddr, SocketAddr}, str::FromStr, time::Duration};
+use tokio::{net::UdpSocket, time::timeout};
+use tracing::{event, Level};
+
+use super::DnsProvider;
+use crate::{
+  configs::environment::{
+    RFC2136_SERVER, RFC2136_TSIG_ALGORITHM, RFC2136_TSIG_KEY, RFC2136_TSIG_KEY_NAME, RFC2136_TTL, RFC2136_ZONE,
+  },
+  types::{model::deployment::Mode, other::voyager_error::VoyagerError},
+  utils::Error,
+};
+
+// Large enough for any answer to an update, which only echoes the zone
+const MAX_RESPONSE_SIZE: usize = 4096;
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+// Accepted difference between our clock and the server's, as recommended by RFC 8945
+const TSIG_FUDGE: u16 = 300;
+
+// Dynamic DNS updates (RFC 2136) sent to the primary server of the zone, e.g. BIND or Knot,
+// signed with TSIG when a key is configured
+pub struct Rfc2136DnsProvider {
+  server: SocketAddr,
+  zone: Name,
+  ttl: u32,
+  signer: Option<TSigner>,
+}
+
+impl Rfc2136DnsProvider {
+  pub fn from_environment() -> Self {
+    let server = RFC2136_SERVER
+      .as_ref()
+      .unwrap_or_else(|| panic!("RFC2136_SERVER is required when DNS_PROVIDER is rfc2136"));
+    let server = SocketAddr::from_str(server)
+      .or_else(|_| IpAddr::from_str(server).map(|ip| SocketAddr::new(ip, 53)))
+      .unwrap_or_else(|_| panic!("Couldn't parse RFC2136_SERVER '{server}' as an address"));
+
+    let zone = RFC2136_ZONE
+      .as_ref()
+      .unwrap_or_else(|| panic!("RFC2136_ZONE is required when DNS_PROVIDER is rfc2136"));
+    let zone = fqdn(zone).unwrap_or_else(|| panic!("Couldn't parse RFC2136_ZONE '{zone}' as a domain name"));
+
+    let signer = RFC2136_TSIG_KEY.as_ref().map(|key| {
+      let key = STANDARD
+        .decode(key.trim())
+        .unwrap_or_else(|e| panic!("Couldn't decode RFC2136_TSIG_KEY as base64: {e}"));
+      let key_name = RFC2136_TSIG_KEY_NAME
+        .as_ref()
+        .and_then(|name| fqdn(name))
+        .unwrap_or_else(|| panic!("RFC2136_TSIG_KEY_NAME must be set to a valid name along with RFC2136_TSIG_KEY"));
+      let algorithm = match RFC2136_TSIG_ALGORITHM.as_str() {
+        "hmac-sha256" => TsigAlgorithm::HmacSha256,
+        "hmac-sha384" => TsigAlgorithm::HmacSha384,
+        "hmac-sha512" => TsigAlgorithm::HmacSha512,
+        algorithm => panic!("Unsupported RFC2136_TSIG_ALGORITHM '{algorithm}', must be one of hmac-sha256, hmac-sha384 or hmac-sha512"),
+      };
+
+      TSigner::new(key, algorithm, key_name, TSIG_FUDGE)
+        .unwrap_or_else(|e| panic!("Couldn't create TSIG signer: {e}"))
+    });
+
+    Self {
+      server,
+      zone,
+      ttl: *RFC2136_TTL,
+      signer,
+    }
+  }
+
+  async fn send(&self, mut message: Message) -> Result<(), VoyagerError> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // TSIG times are 32 bits
+    let now = chrono::Utc::now().timestamp() as u32;
+    let verifier = match &self.signer {
+      Some(signer) => message
+        .finalize(signer, now)
+        .map_err(|e| VoyagerError::rfc2136_request(Box::new(e)))?,
+      None => None,
+    };
+    let request = message.to_vec().map_err(|e| VoyagerError::rfc2136_request(Box::new(e)))?;
+
+    let local = if self.server.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+    let socket = UdpSocket::bind(local)
+      .await
+      .map_err(|e| VoyagerError::rfc2136_request(Box::new(e)))?;
+    socket
+      .connect(self.server)
+      .await
+      .map_err(|e| VoyagerError::rfc2136_request(Box::new(e)))?;
+    socket
+      .send(&request)
+      .await
+      .map_err(|e| VoyagerError::rfc2136_request(Box::new(e)))?;
+
+    let mut buffer = vec![0; MAX_RESPONSE_SIZE];
+    let len = timeout(RESPONSE_TIMEOUT, socket.recv(&mut buffer))
+      .await
+      .map_err(|e| VoyagerError::rfc2136_request(Box::new(e)))?
+      .map_err(|e| VoyagerError::rfc2136_request(Box::new(e)))?;
+    let response = &buffer[..len];
+
+    // Signed updates must have signed answers
+    let response_code = verifier
+      .map_or_else(
+        || Message::from_vec(response).map(|r| r.response_code()),
+        |mut verify| verify(response).map(|r| r.response_code()),
+      )
+      .map_err(|e| VoyagerError::rfc2136_response(Box::new(e)))?;
+
+    if response_code == ResponseCode::NoError {
+      Ok(())
+    } else {
+      Err(VoyagerError::rfc2136_failure(response_code))
+    }
+  }
+}
+
+#[async_trait]
+impl DnsProvider for Rfc2136DnsProvider {
+  async fn add_record(&self, host: &str, ip: &str, mode: Mode) -> Result<Option<String>, VoyagerError> {
+    event!(
+      Level::INFO,
+      "Adding DNS record to {} for host: {}, ip: {}, mode: {}",
+      self.server,
+      host,
+      ip,
+      mode
+    );
+
+    let name = fqdn(host)
+      .filter(|name| self.zone.zone_of(name))
+      .ok_or_else(|| VoyagerError::rfc2136_host(host, &self.zone))?;
+    let rdata = match IpAddr::from_str(ip).map_err(|e| VoyagerError::rfc2136_ip(Box::new(e), ip))? {
+      IpAddr::V4(ip) => RData::A(A(ip)),
+      IpAddr::V6(ip) => RData::AAAA(AAAA(ip)),
+    };
+    let record_type = rdata.record_type();
+
+    // Replaces any record left for the host, so that the update can't fail because of one
+    let mut message = update_message::delete_rrset(Record::with(name.clone(), record_type, 0), self.zone.clone(), false);
+    message.add_update(Record::from_rdata(name.clone(), self.ttl, rdata));
+    self.send(message).await?;
+
+    event!(Level::DEBUG, "Done adding DNS record");
+
+    Ok(Some(format!("{} {record_type}", name.to_ascii())))
+  }
+
+  async fn delete_record(&self, record_id: &str) -> Result<(), VoyagerError> {
+    event!(Level::INFO, "Removing DNS record from {}: {}", self.server, record_id);
+
+    let (name, record_type) = record_id
+      .split_once(' ')
+      .and_then(|(name, record_type)| Some((fqdn(name)?, RecordType::from_str(record_type).ok()?)))
+      .filter(|(name, _)| self.zone.zone_of(name))
+      .ok_or_else(|| VoyagerError::rfc2136_record_id(record_id))?;
+
+    let message = update_message::delete_rrset(Record::with(name, record_type, 0), self.zone.clone(), false);
+    self.send(message).await?;
+
+    event!(Level::DEBUG, "Done removing DNS record");
+
+    Ok(())
+  }
+}
+
+fn fqdn(name: &str) -> Option<Name> {
+  let mut name = Name::from_ascii(name).ok()?;
+  name.set_fqdn(true);
+  Some(name)
+}
+
+impl VoyagerError {
+  fn rfc2136_host(host: &str, zone: &Name) -> Self {
+    Self::new(
+      format!("Host '{host}' is not in the DNS zone '{zone}'"),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn rfc2136_ip(e: Error, ip: &str) -> Self {
+    Self::new(
+      format!("Couldn't parse '{ip}' as an IP address for the DNS record"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn rfc2136_record_id(record_id: &str) -> Self {
+    Self::new(
+      format!("Invalid DNS record id '{record_id}', was it created by another DNS provider?"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      None,
+    )
+  }
+
+  fn rfc2136_request(e: Error) -> Self {
+    Self::new(
+      "Failed to send DNS update".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn rfc2136_response(e: Error) -> Self {
+    Self::new(
+      "Failed to read or verify the answer to the DNS update".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn rfc2136_failure(response_code: ResponseCode) -> Self {
+    Self::new(
+      format!("DNS update was refused: {response_code}"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      None,
+    )
+  }
+}
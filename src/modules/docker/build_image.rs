@@ -1,30 +1,39 @@
 use crate::{
-  configs::environment::DEVELOPMENT, types::other::voyager_error::VoyagerError, utils::{runtime_helpers::RuntimeSpawnHandled, Error}
+  configs::environment::DEVELOPMENT, types::model::deployment::BuildOptions, types::other::{log_stream::LogStream, voyager_error::VoyagerError}, utils::{runtime_helpers::RuntimeSpawnHandled, Error}
 };
 use axum::http::StatusCode;
-use bollard::image::BuildImageOptions;
-use futures::StreamExt;
-use std::{collections::HashMap, path::Path};
+use bollard::query_parameters::BuildImageOptions;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use std::{collections::HashMap, io};
 
 use super::{DOCKER, DOCKER_RUNTIME};
 use tracing::{event, Level};
 
 pub async fn build_image(
-  tar: &Path,
+  context: impl Stream<Item = Result<Bytes, io::Error>> + Send + 'static,
   labels: &[(String, String)],
   extra_hosts: Option<String>,
+  build_options: &BuildOptions,
+  output: &LogStream,
 ) -> Result<String, VoyagerError> {
   let options = BuildImageOptions {
     dockerfile: "Dockerfile".to_string(),
     extrahosts: extra_hosts,
     q: false,
+    nocache: build_options.no_cache,
     forcerm: true,
-    memory: Some(2048 * 1024 * 1024),  // 2GiB
-    memswap: Some(2049 * 1024 * 1024), // 2GiB
-    labels: labels.iter().fold(HashMap::new(), |mut acc, p| {
+    // Were 2GiB and 2GiB plus 1MiB of swap, which don't fit in the i32 Docker's API client takes them as.
+    // These are as close as it gets
+    memory: Some(2047 * 1024 * 1024),
+    memswap: Some(i32::MAX),
+    buildargs: Some(build_options.build_args.clone()),
+    // Empty builds the last stage
+    target: build_options.target.clone().unwrap_or_default(),
+    labels: Some(labels.iter().fold(HashMap::new(), |mut acc, p| {
       acc.insert(p.0.clone(), p.1.clone());
       acc
-    }),
+    })),
     ..Default::default()
   };
 
@@ -35,30 +44,43 @@ pub async fn build_image(
     &options.dockerfile
   );
 
-  let contents = tokio::fs::read(tar).await
-    .map_err(|e| VoyagerError::file_read_error(Box::new(e)))?;
+  let output = output.clone();
   let result = DOCKER_RUNTIME
     .spawn_handled("modules::docker::build_image", async move {
       DOCKER
-        .build_image(options, None, Some(contents.into()))
-        .fold(String::new(), |acc, i| async move {
-          i.map(|build_info| {
+        .build_image(options, None, Some(bollard::body_try_stream(context)))
+        .inspect(|i| match i {
+          Ok(build_info) => {
+            let chunks = [&build_info.stream, &build_info.status, &build_info.error];
+            chunks.into_iter().flatten().for_each(|c| output.push_chunk(c));
+          }
+          Err(e) => output.push_chunk(&e.to_string()),
+        })
+        // Keeps the image id, and the last error Docker reported so it isn't lost if the build fails
+        .fold((String::new(), None), |(id, error), i| async move {
+          match i {
+            Ok(build_info) => {
               if !&*DEVELOPMENT {
-                event!(Level::INFO, "Response: {:?}", build_info.stream.unwrap_or_default());
+                event!(Level::INFO, "Response: {:?}", build_info.stream.clone().unwrap_or_default());
               }
-              build_info.aux.map(|i| i.id).and_then(|i| i)
-            })
-            .map_or_else(|e| {
+              let error = build_info.error.or(error);
+              let id = build_info.aux.and_then(|i| i.id).unwrap_or(id);
+              (id, error)
+            }
+            Err(e) => {
+              let error = Some(e.to_string());
               VoyagerError::intermediate_build_image(Box::new(e));
-              acc.clone()
-            }, |i| i.unwrap_or_else(|| acc.clone())) 
+              (id, error)
+            }
+          }
         })
         .await
     })
     .await?;
 
+  let (result, error) = result;
   let result = (if result.is_empty() {
-    Err(VoyagerError::build_image())
+    Err(VoyagerError::build_image(error))
   } else {
     Ok(result)
   })?;
@@ -69,15 +91,6 @@ pub async fn build_image(
 }
 
 impl VoyagerError {
-  fn file_read_error(e: Error) -> Self {
-    Self::new(
-      "Failed to read tar file!".to_string(),
-      StatusCode::INTERNAL_SERVER_ERROR,
-      false,
-      Some(e),
-    )
-  }
-
   fn intermediate_build_image(e: Error) -> Self {
     Self::new(
       "Error during Docker Image Build intermediate steps".to_string(),
@@ -87,9 +100,9 @@ impl VoyagerError {
     )
   }
 
-  fn build_image() -> Self {
+  fn build_image(error: Option<String>) -> Self {
     Self::new(
-      "Failed to build image!".to_string(),
+      error.map_or_else(|| "Failed to build image!".to_string(), |e| format!("Failed to build image! {}", e.trim())),
       StatusCode::INTERNAL_SERVER_ERROR,
       false,
       None,
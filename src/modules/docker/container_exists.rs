@@ -0,0 +1,39 @@
+use axum::http::StatusCode;
+use bollard::{errors::Error as BollardError, query_parameters::InspectContainerOptions};
+use tracing::{event, Level};
+
+use crate::{
+  modules::docker::{DOCKER, DOCKER_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+
+pub async fn container_exists(container_name: String) -> Result<bool, VoyagerError> {
+  event!(
+    Level::DEBUG,
+    "Checking if container with name {container_name} exists"
+  );
+
+  let result = DOCKER_RUNTIME
+    .spawn_handled("modules::docker::container_exists", async move {
+      DOCKER.inspect_container(&container_name, None::<InspectContainerOptions>).await
+    })
+    .await?;
+
+  match result {
+    Ok(_) => Ok(true),
+    Err(BollardError::DockerResponseServerError { status_code: 404, .. }) => Ok(false),
+    Err(e) => Err(VoyagerError::container_exists(Box::new(e))),
+  }
+}
+
+impl VoyagerError {
+  fn container_exists(e: Error) -> Self {
+    Self::new(
+      "Failed to inspect container".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
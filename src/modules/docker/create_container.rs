@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
 use crate::{
-  types::other::voyager_error::VoyagerError,
+  types::{model::deployment::ResourceLimits, other::voyager_error::VoyagerError},
   utils::{runtime_helpers::RuntimeSpawnHandled, Error},
 };
 use axum::http::StatusCode;
-use bollard::{container::{Config, CreateContainerOptions, NetworkingConfig}, secret::EndpointSettings};
+use bollard::{models::{ContainerCreateBody, EndpointSettings, HostConfig, NetworkingConfig}, query_parameters::CreateContainerOptions};
 use tracing::{event, Level};
 
 use super::{DOCKER, DOCKER_RUNTIME};
@@ -15,6 +15,8 @@ pub async fn create_container(
   // port: u16,
   // internal_port: u16,
   docker_image: &str,
+  env: Vec<String>,
+  limits: &ResourceLimits,
 ) -> Result<String, VoyagerError> {
   event!(
     Level::INFO,
@@ -32,26 +34,35 @@ pub async fn create_container(
   //   ..Default::default()
   // };
 
-  let config = Config {
+  let host_config = HostConfig {
+    nano_cpus: limits.cpus.map(nano_cpus),
+    memory: limits.memory_mb.map(mb_to_bytes),
+    memory_swap: limits.memory_swap_mb.map(mb_to_bytes),
+    pids_limit: limits.pids,
+    ..Default::default()
+  };
+
+  let config = ContainerCreateBody {
     image: Some(docker_image.to_string()),
-    // host_config: Some(host_config),
+    env: Some(env),
+    host_config: Some(host_config),
     networking_config: Some(
       NetworkingConfig {
-        endpoints_config: HashMap::from([(
+        endpoints_config: Some(HashMap::from([(
           "traefik-net".to_string(),
           EndpointSettings {
             aliases: Some(vec![name.clone()]),
             ..Default::default()
           },
-        )]),
+        )])),
       }
     ),
     ..Default::default()
   };
 
   let options = Some(CreateContainerOptions {
-    name,
-    platform: Some("linux/amd64".to_string()),
+    name: Some(name),
+    platform: "linux/amd64".to_string(),
   });
 
   let result = DOCKER_RUNTIME
@@ -70,6 +81,16 @@ pub async fn create_container(
   Ok(result)
 }
 
+#[allow(clippy::cast_possible_truncation)] // Fractions of a billionth of a CPU are meaningless
+pub(super) fn nano_cpus(cpus: f64) -> i64 {
+  (cpus * 1_000_000_000.0) as i64
+}
+
+// Keeps -1 as is, as it means unlimited
+pub(super) const fn mb_to_bytes(mb: i64) -> i64 {
+  if mb < 0 { mb } else { mb.saturating_mul(1024 * 1024) }
+}
+
 impl VoyagerError {
   fn create_container(e: Error) -> Self {
     Self::new(
@@ -3,7 +3,7 @@ use crate::types::other::voyager_error::VoyagerError;
 use crate::utils::runtime_helpers::RuntimeSpawnHandled;
 use crate::utils::Error;
 use axum::http::StatusCode;
-use bollard::container::RemoveContainerOptions;
+use bollard::query_parameters::RemoveContainerOptions;
 use tracing::{event, Level};
 
 pub async fn delete_container(container_name: String) -> Result<(), VoyagerError> {
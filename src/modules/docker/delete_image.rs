@@ -3,7 +3,7 @@ use crate::types::other::voyager_error::VoyagerError;
 use crate::utils::runtime_helpers::RuntimeSpawnHandled;
 use crate::utils::Error;
 use axum::http::StatusCode;
-use bollard::image::RemoveImageOptions;
+use bollard::query_parameters::RemoveImageOptions;
 use tracing::{event, Level};
 
 pub async fn delete_image(image_id: String) -> Result<(), VoyagerError> {
@@ -0,0 +1,57 @@
+use futures::StreamExt;
+use tokio::sync::mpsc;
+use tracing::{event, Level};
+
+use crate::{
+  modules::docker::{DOCKER, DOCKER_RUNTIME},
+  types::other::container_logs::{ContainerLog, ContainerLogsOptions},
+};
+
+use super::{logs_options, to_container_logs};
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+// Keeps reading the logs of a container until it stops, or until the receiver is dropped
+pub fn follow_logs(container_name: String, options: ContainerLogsOptions) -> mpsc::Receiver<ContainerLog> {
+  event!(
+    Level::INFO,
+    "Following logs for container with name {}",
+    container_name
+  );
+
+  let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+
+  DOCKER_RUNTIME.spawn(async move {
+    let logs = DOCKER.logs(&container_name, Some(logs_options(options, true)));
+    tokio::pin!(logs);
+
+    'follow: loop {
+      let next = tokio::select! {
+        next = logs.next() => next,
+        () = sender.closed() => break,
+      };
+
+      match next {
+        Some(Ok(d)) => {
+          for log in to_container_logs(&d) {
+            if sender.send(log).await.is_err() {
+              break 'follow;
+            }
+          }
+        }
+        Some(Err(e)) => {
+          event!(Level::ERROR, "Error trying to follow logs: {:?}", e);
+          break;
+        }
+        None => break,
+      }
+    }
+
+    event!(
+      Level::DEBUG,
+      "Done following logs for container with name {container_name}"
+    );
+  });
+
+  receiver
+}
@@ -1,34 +1,31 @@
-use bollard::container::LogsOptions;
+use bollard::{container::LogOutput, query_parameters::LogsOptions};
 use futures::StreamExt;
 use tracing::{event, Level};
 
 use crate::{
   modules::docker::{DOCKER, DOCKER_RUNTIME},
-  types::other::voyager_error::VoyagerError,
+  types::other::{
+    container_logs::{ContainerLog, ContainerLogsOptions, LogSource},
+    voyager_error::VoyagerError,
+  },
   utils::runtime_helpers::RuntimeSpawnHandled,
 };
 
-pub async fn get_logs(container_name: &str) -> Result<Vec<String>, VoyagerError> {
+pub async fn get_logs(container_name: &str, options: ContainerLogsOptions) -> Result<Vec<ContainerLog>, VoyagerError> {
   event!(
     Level::INFO,
     "Getting logs for container with name {}",
     container_name
   );
 
-  let options = LogsOptions::<String> {
-    stdout: true,
-    stderr: true,
-    ..Default::default()
-  };
-
   let logs = DOCKER_RUNTIME
     .spawn_handled(
       "modules::docker::get_logs",
       DOCKER
-        .logs(container_name, Some(options))
+        .logs(container_name, Some(logs_options(options, false)))
         .fold(Vec::new(), |mut acc, i| async {
           match i {
-            Ok(d) => acc.push(d.to_string()),
+            Ok(d) => acc.extend(to_container_logs(&d)),
             Err(e) => event!(Level::ERROR, "Error trying to read logs: {:?}", e),
           }
 
@@ -44,3 +41,60 @@ pub async fn get_logs(container_name: &str) -> Result<Vec<String>, VoyagerError>
 
   Ok(logs)
 }
+
+pub(super) fn logs_options(options: ContainerLogsOptions, follow: bool) -> LogsOptions {
+  LogsOptions {
+    follow,
+    stdout: true,
+    stderr: true,
+    since: options.since.map_or(0, to_docker_timestamp),
+    until: options.until.map_or(0, to_docker_timestamp),
+    timestamps: options.timestamps,
+    tail: options.tail.map_or_else(|| "all".to_string(), |t| t.to_string()),
+  }
+}
+
+// Docker's API client only takes timestamps up to 2038, so later ones are as far as it goes
+fn to_docker_timestamp(timestamp: i64) -> i32 {
+  i32::try_from(timestamp).unwrap_or(if timestamp < 0 { 0 } else { i32::MAX })
+}
+
+// A chunk of output can hold several lines, which end with "\r\n" when the container has a TTY
+pub(super) fn to_container_logs(output: &LogOutput) -> Vec<ContainerLog> {
+  let source = match output {
+    LogOutput::StdOut { .. } => LogSource::Stdout,
+    LogOutput::StdErr { .. } => LogSource::Stderr,
+    LogOutput::StdIn { .. } | LogOutput::Console { .. } => LogSource::Console,
+  };
+
+  output
+    .to_string()
+    .split_terminator('\n')
+    .map(|line| line.trim_end_matches('\r'))
+    // A carriage return within a line redraws it, and can't be sent as a Server-Sent Event
+    .flat_map(|line| line.split('\r').filter(move |part| !part.is_empty() || line.is_empty()))
+    .map(|message| ContainerLog { source, message: message.to_string() })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use bollard::container::LogOutput;
+
+  use super::{to_container_logs, to_docker_timestamp};
+
+  #[test]
+  fn to_container_logs_splits_lines_of_a_tty() {
+    let output = LogOutput::Console { message: "a\r\n\r\nb 10%\rb 100%\r\n".into() };
+    let messages = to_container_logs(&output).into_iter().map(|log| log.message).collect::<Vec<_>>();
+
+    assert_eq!(messages, ["a", "", "b 10%", "b 100%"]);
+  }
+
+  #[test]
+  fn to_docker_timestamp_clamps_to_i32() {
+    assert_eq!(to_docker_timestamp(1_710_339_024), 1_710_339_024);
+    assert_eq!(to_docker_timestamp(4_102_444_800), i32::MAX);
+    assert_eq!(to_docker_timestamp(i64::MIN), 0);
+  }
+}
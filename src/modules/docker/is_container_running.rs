@@ -1,4 +1,5 @@
 use axum::http::StatusCode;
+use bollard::query_parameters::InspectContainerOptions;
 use tracing::{event, Level};
 
 use crate::{
@@ -15,7 +16,7 @@ pub async fn is_container_running(container_name: String) -> Result<bool, Voyage
 
   let result = DOCKER_RUNTIME
     .spawn_handled("modules::docker::is_container_running", async move {
-      DOCKER.inspect_container(&container_name, None).await
+      DOCKER.inspect_container(&container_name, None::<InspectContainerOptions>).await
     })
     .await?
     .map_err(|e| VoyagerError::inspect_container(Box::new(e)))?;
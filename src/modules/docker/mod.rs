@@ -1,6 +1,9 @@
 mod build_image;
 pub use build_image::*;
 
+mod container_exists;
+pub use container_exists::*;
+
 mod create_container;
 pub use create_container::*;
 
@@ -10,6 +13,9 @@ pub use delete_container::*;
 mod delete_image;
 pub use delete_image::*;
 
+mod follow_logs;
+pub use follow_logs::*;
+
 mod get_internal_port;
 pub use get_internal_port::*;
 
@@ -19,7 +25,12 @@ pub use get_logs::*;
 mod is_container_running;
 pub use is_container_running::*;
 
+mod rename_container;
+pub use rename_container::*;
+
 mod restart_container;
+pub use restart_container::*;
+
 
 mod start_container;
 pub use start_container::*;
@@ -27,6 +38,9 @@ pub use start_container::*;
 mod stop_container;
 pub use stop_container::*;
 
+mod update_container_resources;
+pub use update_container_resources::*;
+
 use crate::utils::Error;
 use crate::utils::ExpectError;
 
@@ -0,0 +1,40 @@
+use axum::http::StatusCode;
+use bollard::query_parameters::RenameContainerOptions;
+use tracing::{event, Level};
+
+use crate::{
+  modules::docker::{DOCKER, DOCKER_RUNTIME},
+  types::other::voyager_error::VoyagerError,
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+
+pub async fn rename_container(container_name: String, new_name: String) -> Result<(), VoyagerError> {
+  event!(
+    Level::INFO,
+    "Renaming container with name {container_name} to {new_name}"
+  );
+
+  DOCKER_RUNTIME
+    .spawn_handled("modules::docker::rename_container", async move {
+      DOCKER
+        .rename_container(&container_name, RenameContainerOptions { name: new_name })
+        .await
+    })
+    .await?
+    .map_err(|e| VoyagerError::rename_container(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done renaming container.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn rename_container(e: Error) -> Self {
+    Self::new(
+      "Failed to rename container".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -1,4 +1,5 @@
 use axum::http::StatusCode;
+use bollard::query_parameters::RestartContainerOptions;
 use tracing::{event, Level};
 
 use crate::{
@@ -7,7 +8,6 @@ use crate::{
   utils::{runtime_helpers::RuntimeSpawnHandled, Error},
 };
 
-#[allow(unused)]
 pub async fn restart_container(container_name: String) -> Result<(), VoyagerError> {
   event!(
     Level::INFO,
@@ -17,7 +17,7 @@ pub async fn restart_container(container_name: String) -> Result<(), VoyagerErro
 
   DOCKER_RUNTIME
     .spawn_handled("modules::docker::restart_container", async move {
-      DOCKER.restart_container(&container_name, None).await
+      DOCKER.restart_container(&container_name, None::<RestartContainerOptions>).await
     })
     .await?
     .map_err(|e| VoyagerError::restart_container(Box::new(e)))?;
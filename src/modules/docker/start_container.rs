@@ -1,5 +1,5 @@
 use axum::http::StatusCode;
-use bollard::container::StartContainerOptions;
+use bollard::query_parameters::StartContainerOptions;
 use tracing::{event, Level};
 
 use crate::{
@@ -18,7 +18,7 @@ pub async fn start_container(container_name: String) -> Result<(), VoyagerError>
   DOCKER_RUNTIME
     .spawn_handled("modules::docker::start_container", async move {
       DOCKER
-        .start_container(&container_name, None::<StartContainerOptions<String>>)
+        .start_container(&container_name, None::<StartContainerOptions>)
         .await
     })
     .await?
@@ -1,4 +1,5 @@
 use axum::http::StatusCode;
+use bollard::query_parameters::StopContainerOptions;
 use tracing::{event, Level};
 
 use crate::{
@@ -16,7 +17,7 @@ pub async fn stop_container(container_name: String) -> Result<(), VoyagerError>
 
   DOCKER_RUNTIME
     .spawn_handled("modules::docker::stop_container", async move {
-      DOCKER.stop_container(&container_name, None).await
+      DOCKER.stop_container(&container_name, None::<StopContainerOptions>).await
     })
     .await?
     .map_err(|e| VoyagerError::stop_container(Box::new(e)))?;
@@ -0,0 +1,49 @@
+use axum::http::StatusCode;
+use bollard::models::ContainerUpdateBody;
+use tracing::{event, Level};
+
+use crate::{
+  modules::docker::{DOCKER, DOCKER_RUNTIME},
+  types::{model::deployment::ResourceLimits, other::voyager_error::VoyagerError},
+  utils::{runtime_helpers::RuntimeSpawnHandled, Error},
+};
+
+use super::{mb_to_bytes, nano_cpus};
+
+// Unset limits are left as they are, Docker can't remove limits from a created container
+pub async fn update_container_resources(container_name: String, limits: ResourceLimits) -> Result<(), VoyagerError> {
+  event!(
+    Level::INFO,
+    "Updating resource limits of container with name {container_name}: {limits:?}"
+  );
+
+  let options = ContainerUpdateBody {
+    nano_cpus: limits.cpus.map(nano_cpus),
+    memory: limits.memory_mb.map(mb_to_bytes),
+    memory_swap: limits.memory_swap_mb.map(mb_to_bytes),
+    pids_limit: limits.pids,
+    ..Default::default()
+  };
+
+  DOCKER_RUNTIME
+    .spawn_handled("modules::docker::update_container_resources", async move {
+      DOCKER.update_container(&container_name, options).await
+    })
+    .await?
+    .map_err(|e| VoyagerError::update_container_resources(Box::new(e)))?;
+
+  event!(Level::DEBUG, "Done updating container resource limits.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn update_container_resources(e: Error) -> Self {
+    Self::new(
+      "Failed to update container resource limits".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
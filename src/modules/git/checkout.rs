@@ -0,0 +1,44 @@
+use axum::http::StatusCode;
+use git2::{build::CheckoutBuilder, Oid, Repository};
+use std::path::Path;
+use tracing::{event, Level};
+
+use crate::{
+  types::other::{log_stream::LogStream, voyager_error::VoyagerError},
+  utils::Error,
+};
+
+// Writes the files of a commit of the mirror to `path`, a plain directory without any git metadata
+pub fn checkout(repo: &Repository, sha: &str, path: &Path, output: &LogStream) -> Result<(), VoyagerError> {
+  event!(Level::INFO, "Checking out {sha} into {}", path.display());
+  output.push(format!("Checking out {sha}"));
+
+  let commit = Oid::from_str(sha)
+    .and_then(|oid| repo.find_commit(oid))
+    .map_err(|e| VoyagerError::checkout(Box::new(e), sha))?;
+
+  std::fs::create_dir_all(path).map_err(|e| VoyagerError::checkout(Box::new(e), sha))?;
+
+  // The mirror is bare, so its index is left alone
+  repo
+    .checkout_tree(
+      commit.as_object(),
+      Some(CheckoutBuilder::new().target_dir(path).update_index(false).force()),
+    )
+    .map_err(|e| VoyagerError::checkout(Box::new(e), sha))?;
+
+  event!(Level::DEBUG, "Done checking out {sha}.");
+
+  Ok(())
+}
+
+impl VoyagerError {
+  fn checkout(e: Error, sha: &str) -> Self {
+    Self::new(
+      format!("Failed to check out '{sha}'"),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
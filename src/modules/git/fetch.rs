@@ -0,0 +1,183 @@
+use axum::http::StatusCode;
+use git2::{AutotagOption, Cred, CredentialType, FetchOptions, RemoteCallbacks, Repository};
+use lazy_static::lazy_static;
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex, PoisonError},
+};
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+use tracing::{event, Level};
+
+use crate::{
+  types::other::{git_auth::GitAuth, git_remote::GitRemote, log_stream::LogStream, voyager_error::VoyagerError},
+  utils::Error,
+};
+
+lazy_static! {
+  // Deployments of the same repository can't use its mirror at the same time
+  static ref MIRROR_LOCKS: Mutex<HashMap<PathBuf, Arc<AsyncMutex<()>>>> = Mutex::new(HashMap::new());
+}
+
+// A fetched mirror, locked until dropped so that no other fetch moves its branch while the commit is
+// resolved and checked out
+pub struct Mirror {
+  pub repo: Repository,
+  _lock: OwnedMutexGuard<()>,
+}
+
+// Where the branch is kept in the mirror. The remote's default branch is fetched from its HEAD
+pub(super) fn local_ref(branch: Option<&str>) -> String {
+  branch.map_or_else(|| "refs/voyager/default".to_string(), |branch| format!("refs/heads/{branch}"))
+}
+
+// Brings a bare mirror of the repository under `mirrors_dir` up to date with the branch, only receiving the
+// objects it doesn't have yet, and creates the mirror on first use.
+// Tags are only fetched for deployments pinned to a ref, which also need the whole history of the branch.
+// It blocks on the network and on other deployments of the repository, so it must run outside of async tasks
+pub fn fetch(
+  remote: &GitRemote,
+  auth: Option<&GitAuth>,
+  branch: Option<&str>,
+  git_ref: Option<&str>,
+  depth: Option<i32>,
+  mirrors_dir: &Path,
+  output: &LogStream,
+) -> Result<Mirror, VoyagerError> {
+  event!(Level::INFO, "Fetching repository: {}", remote.url);
+  output.push(format!("Fetching repository {}", remote.url));
+
+  // libgit2 only fetches shallow over HTTP, and keeps a single shallow boundary per repository.
+  // So shallow mirrors hold a single branch, and the others the whole history of every deployed branch
+  let name = escape(&remote.url);
+  let depth = depth.filter(|depth| *depth > 0 && git_ref.is_none() && !remote.ssh);
+  let mirror_path = match depth {
+    Some(_) => mirrors_dir.join(format!("{name}@{}.shallow.git", branch.map_or_else(|| "HEAD".to_string(), escape))),
+    None => mirrors_dir.join(format!("{name}.git")),
+  };
+
+  let lock = {
+    let mut locks = MIRROR_LOCKS.lock().unwrap_or_else(PoisonError::into_inner);
+    // Only the locks of mirrors in use or waited for are kept
+    locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+    locks.entry(mirror_path.clone()).or_default().clone()
+  };
+  let lock = lock.blocking_lock_owned();
+
+  let created = !mirror_path.exists();
+  let repo = if created {
+    output.push("Creating repository cache.");
+    Repository::init_bare(&mirror_path).map_err(|e| VoyagerError::open_mirror(Box::new(e)))?
+  } else {
+    Repository::open_bare(&mirror_path).map_err(|e| VoyagerError::open_mirror(Box::new(e)))?
+  };
+
+  // Configure authentication. libgit2 asks again whenever the credentials are refused, so they are only given once
+  let mut callbacks = RemoteCallbacks::new();
+  let mut attempted = false;
+  callbacks.credentials(|_url, username_from_url, allowed_types| {
+    if std::mem::replace(&mut attempted, true) {
+      return Err(git2::Error::from_str("The credentials were refused"));
+    }
+
+    let username = username_from_url.unwrap_or("git");
+    match auth {
+      Some(GitAuth::Token { username, token }) => Cred::userpass_plaintext(username, token),
+      Some(GitAuth::SshKey { private_key, passphrase }) => {
+        Cred::ssh_key_from_memory(username, None, private_key, passphrase.as_deref())
+      },
+      None if allowed_types.contains(CredentialType::SSH_KEY) => Cred::ssh_key_from_agent(username),
+      None => Err(git2::Error::from_str("No credentials are registered for this repository")),
+    }
+  });
+
+  // Reports progress every 10% of received objects
+  let mut reported = None;
+  callbacks.transfer_progress(|stats| {
+    let total = stats.total_objects().max(1);
+    let percent = stats.received_objects() * 100 / total;
+    if reported != Some(percent / 10) {
+      reported = Some(percent / 10);
+      output.push(format!(
+        "Receiving objects: {percent}% ({}/{})",
+        stats.received_objects(),
+        stats.total_objects()
+      ));
+    }
+    true
+  });
+
+  let mut fo = FetchOptions::new();
+  fo.remote_callbacks(callbacks);
+  fo.download_tags(AutotagOption::None);
+  if let Some(depth) = depth {
+    fo.depth(depth);
+  }
+
+  let source = branch.map_or_else(|| "HEAD".to_string(), |branch| format!("refs/heads/{branch}"));
+  let mut refspecs = vec![format!("+{source}:{}", local_ref(branch))];
+  if git_ref.is_some() {
+    refspecs.push("+refs/tags/*:refs/tags/*".to_string());
+  }
+
+  // Each mirror is only ever fetched from its own URL, so it needs no named remote
+  let result = repo
+    .remote_anonymous(&remote.url)
+    .and_then(|mut origin| {
+      origin.fetch(&refspecs, Some(&mut fo), None)?;
+      Ok(origin.list()?.iter().any(|head| head.name() == source))
+    })
+    .map_err(|e| VoyagerError::fetch(Box::new(e)))
+    // A refspec that matches nothing is no error, and would leave a deleted branch at its last fetched commit
+    .and_then(|advertised| advertised.then_some(()).ok_or_else(|| VoyagerError::missing_branch(branch.unwrap_or("HEAD"))));
+
+  // So that a mistyped repository or branch doesn't leave an empty mirror behind
+  if let Err(e) = result {
+    if created {
+      let _ = std::fs::remove_dir_all(&mirror_path);
+    }
+    return Err(e);
+  }
+
+  event!(Level::DEBUG, "Done fetching repository.");
+
+  Ok(Mirror { repo, _lock: lock })
+}
+
+// URLs and branches have characters that don't belong in a directory name. They are escaped rather than
+// replaced, so that different branches never share a shallow mirror
+fn escape(name: &str) -> String {
+  name
+    .bytes()
+    .map(|b| if b.is_ascii_alphanumeric() || b == b'-' || b == b'.' { char::from(b).to_string() } else { format!("%{b:02x}") })
+    .collect()
+}
+
+impl VoyagerError {
+  fn open_mirror(e: Error) -> Self {
+    Self::new(
+      "Failed to open repository cache".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn missing_branch(branch: &str) -> Self {
+    Self::new(
+      format!("Couldn't find branch '{branch}' in the repository"),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn fetch(e: Error) -> Self {
+    Self::new(
+      "Failed to fetch git repository!".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -1,3 +1,9 @@
-mod clone;
+mod checkout;
+mod fetch;
+mod parse_remote;
+mod resolve_commit;
 
-pub use clone::*;
+pub use checkout::*;
+pub use fetch::*;
+pub use parse_remote::*;
+pub use resolve_commit::*;
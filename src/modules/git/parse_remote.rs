@@ -0,0 +1,103 @@
+use axum::http::StatusCode;
+use url::Url;
+
+use crate::{
+  configs::environment::GIT_DEFAULT_HOST,
+  types::other::{git_remote::GitRemote, voyager_error::VoyagerError},
+};
+
+// Accepts HTTPS and SSH URLs, the scp-like syntax of SSH (git@host:owner/name.git),
+// and owner/name for repositories of GIT_DEFAULT_HOST
+pub fn parse_remote(repo_url: &str) -> Result<GitRemote, VoyagerError> {
+  let repo_url = repo_url.trim();
+
+  if repo_url.contains("://") {
+    let url = Url::parse(repo_url).map_err(|_| VoyagerError::invalid_remote(repo_url))?;
+    let ssh = match url.scheme() {
+      "https" | "http" => false,
+      "ssh" => true,
+      scheme => return Err(VoyagerError::remote_scheme(scheme)),
+    };
+    // Credentials are looked up by host instead, so that they don't end up in the deployments
+    if !ssh && (!url.username().is_empty() || url.password().is_some()) {
+      return Err(VoyagerError::remote_credentials());
+    }
+
+    let host = url.host_str().ok_or_else(|| VoyagerError::invalid_remote(repo_url))?;
+    let owner = owner(url.path()).ok_or_else(|| VoyagerError::invalid_remote(repo_url))?;
+    return Ok(GitRemote {
+      url: repo_url.to_string(),
+      host: host.to_lowercase(),
+      owner,
+      path: normalise_path(url.path()),
+      ssh,
+      insecure: url.scheme() == "http",
+    });
+  }
+
+  if let Some((user_host, path)) = repo_url.split_once(':') {
+    let host = user_host
+      .split_once('@')
+      .map(|(_, host)| host)
+      .filter(|host| !host.is_empty() && !host.contains('/'))
+      .ok_or_else(|| VoyagerError::invalid_remote(repo_url))?;
+    let owner = owner(path).ok_or_else(|| VoyagerError::invalid_remote(repo_url))?;
+    return Ok(GitRemote {
+      url: repo_url.to_string(),
+      host: host.to_lowercase(),
+      owner,
+      path: normalise_path(path),
+      ssh: true,
+      insecure: false,
+    });
+  }
+
+  if repo_url.contains('@') || repo_url.split('/').count() != 2 {
+    return Err(VoyagerError::invalid_remote(repo_url));
+  }
+  let remote = parse_remote(&format!("{}/{repo_url}.git", GIT_DEFAULT_HOST.trim_end_matches('/')))?;
+  Ok(remote)
+}
+
+// The first segment of a path holding at least the owner and the name
+fn owner(path: &str) -> Option<String> {
+  let mut segments = path.trim_start_matches('/').split('/');
+  let owner = segments.next().filter(|owner| !owner.is_empty())?;
+  segments.next().filter(|name| !name.is_empty())?;
+  Some(owner.to_lowercase())
+}
+
+// So that the HTTPS and SSH URLs of a repository have the same path
+fn normalise_path(path: &str) -> String {
+  let path = path.trim_matches('/');
+  path.strip_suffix(".git").unwrap_or(path).trim_end_matches('/').to_lowercase()
+}
+
+impl VoyagerError {
+  fn invalid_remote(repo_url: &str) -> Self {
+    Self::new(
+      format!("Invalid repository '{repo_url}', expected owner/name, an HTTPS URL or an SSH URL"),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn remote_scheme(scheme: &str) -> Self {
+    Self::new(
+      format!("Unsupported repository URL scheme '{scheme}', expected https or ssh"),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn remote_credentials() -> Self {
+    Self::new(
+      "Repository URLs can't hold credentials, register them with /git-credentials instead".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,80 @@
+use axum::http::StatusCode;
+use git2::{ObjectType, Repository};
+
+use super::fetch::local_ref;
+use crate::{
+  types::{model::deployment::Commit, other::voyager_error::VoyagerError},
+  utils::Error,
+};
+
+// The commit to deploy: the fetched head of the branch, or the tag or commit SHA (possibly abbreviated) the
+// deployment is pinned to. The mirror holds every branch deployed from the repository, so a pinned commit must
+// be part of the history of the branch, and is never looked up among the other ones
+pub fn resolve_commit(repo: &Repository, branch: Option<&str>, git_ref: Option<&str>) -> Result<Commit, VoyagerError> {
+  let head = repo
+    .find_reference(&local_ref(branch))
+    .and_then(|reference| reference.peel_to_commit())
+    .map_err(|e| VoyagerError::resolve_branch(Box::new(e)))?;
+
+  let commit = match git_ref {
+    Some(git_ref) => {
+      let commit = find_tag_or_sha(repo, git_ref).map_err(|e| VoyagerError::resolve_ref(Box::new(e), git_ref))?;
+      let on_branch = commit.id() == head.id()
+        || repo
+          .graph_descendant_of(head.id(), commit.id())
+          .map_err(|e| VoyagerError::resolve_ref(Box::new(e), git_ref))?;
+      if !on_branch {
+        return Err(VoyagerError::ref_not_on_branch(git_ref, branch));
+      }
+      commit
+    },
+    None => head,
+  };
+  let author = commit.author();
+  Ok(Commit {
+    sha: commit.id().to_string(),
+    author: format!("{} <{}>", author.name().unwrap_or_default(), author.email().unwrap_or_default()),
+    message: commit.message().unwrap_or_default().trim().to_string(),
+  })
+}
+
+fn find_tag_or_sha<'r>(repo: &'r Repository, git_ref: &str) -> Result<git2::Commit<'r>, git2::Error> {
+  if let Ok(tag) = repo.find_reference(&format!("refs/tags/{git_ref}")) {
+    return tag.peel_to_commit();
+  }
+  if !(4..=40).contains(&git_ref.len()) || !git_ref.chars().all(|c| c.is_ascii_hexdigit()) {
+    return Err(git2::Error::from_str("Neither a tag nor a commit SHA"));
+  }
+  repo
+    .find_object_by_prefix(git_ref, Some(ObjectType::Commit))
+    .and_then(|object| object.peel_to_commit())
+}
+
+impl VoyagerError {
+  fn resolve_ref(e: Error, git_ref: &str) -> Self {
+    Self::new(
+      format!("Couldn't find commit or tag '{git_ref}' in the repository"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+
+  fn ref_not_on_branch(git_ref: &str, branch: Option<&str>) -> Self {
+    Self::new(
+      format!("Commit or tag '{git_ref}' is not on branch '{}'", branch.unwrap_or("default")),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn resolve_branch(e: Error) -> Self {
+    Self::new(
+      "Failed to resolve the fetched commit".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+}
@@ -1,5 +1,6 @@
 pub mod cloudflare;
-pub mod discord;
+pub mod dns;
 pub mod docker;
 pub mod git;
+pub mod notifications;
 pub mod tar;
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use serenity::all::Webhook;
+use serenity::builder::CreateEmbed;
+use serenity::builder::ExecuteWebhook;
+use serenity::http::Http;
+use tracing::event;
+use tracing::Level;
+
+use super::Notifier;
+use crate::configs::environment::DISCORD_WEBHOOK;
+use crate::types::other::notification::Notification;
+use crate::types::other::voyager_error::VoyagerError;
+use crate::utils::Error;
+
+pub struct DiscordNotifier {
+  webhook_url: String,
+}
+
+impl DiscordNotifier {
+  pub fn from_environment() -> Self {
+    let webhook_url = DISCORD_WEBHOOK
+      .clone()
+      .unwrap_or_else(|| panic!("DISCORD_WEBHOOK is required when NOTIFIERS includes discord"));
+
+    Self { webhook_url }
+  }
+}
+
+#[async_trait]
+impl Notifier for DiscordNotifier {
+  fn name(&self) -> &'static str {
+    "discord"
+  }
+
+  async fn notify(&self, notification: &Notification) -> Result<(), VoyagerError> {
+    event!(
+      Level::INFO,
+      "Sending {} discord message for deployment {}",
+      notification.event,
+      notification.deployment_id
+    );
+
+    let embed = CreateEmbed::new()
+      .title(format!("[{}](https://{})", notification.title(), notification.host))
+      .description(notification.description())
+      .field("ID", &notification.deployment_id, true)
+      .field("Docker Container", &notification.container_name, true);
+    let builder = ExecuteWebhook::new().username("Voyager API").embed(embed);
+
+    let http = Http::new("");
+    let webhook_client = Webhook::from_url(&http, &self.webhook_url)
+      .await
+      .map_err(|e| VoyagerError::create_discord_client(Box::new(e)))?;
+
+    let msg = webhook_client
+      .execute(&http, false, builder)
+      .await
+      .map_err(|e| VoyagerError::execute_discord_webhook(Box::new(e)))?;
+
+    let message = msg.map_or(String::new(), |msg| {
+      format!(" Returned message is: {}", msg.content)
+    });
+
+    event!(Level::DEBUG, "Done sending Discord Webhook.{message}");
+
+    Ok(())
+  }
+}
+
+impl VoyagerError {
+  fn create_discord_client(e: Error) -> Self {
+    Self::new(
+      "Failed to create Discord client".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      true,
+      Some(e),
+    )
+  }
+
+  fn execute_discord_webhook(e: Error) -> Self {
+    Self::new(
+      "Failed to send Discord webhook".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      true,
+      Some(e),
+    )
+  }
+}
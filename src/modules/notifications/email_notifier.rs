@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use lettre::{
+  message::{header::ContentType, Mailbox},
+  transport::smtp::authentication::Credentials,
+  AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use tracing::{event, Level};
+
+use super::Notifier;
+use crate::{
+  configs::environment::{SMTP_FROM, SMTP_HOST, SMTP_PASSWORD, SMTP_PORT, SMTP_TLS, SMTP_TO, SMTP_USERNAME},
+  types::other::{notification::Notification, voyager_error::VoyagerError},
+  utils::Error,
+};
+
+// Sends notifications by email through an SMTP relay
+pub struct EmailNotifier {
+  transport: AsyncSmtpTransport<Tokio1Executor>,
+  from: Mailbox,
+  to: Vec<Mailbox>,
+}
+
+impl EmailNotifier {
+  pub fn from_environment() -> Self {
+    let host = SMTP_HOST
+      .as_deref()
+      .unwrap_or_else(|| panic!("SMTP_HOST is required when NOTIFIERS includes email"));
+
+    let mut builder = match SMTP_TLS.as_str() {
+      "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+        .unwrap_or_else(|e| panic!("Couldn't set up STARTTLS for SMTP_HOST '{host}': {e}")),
+      "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host)
+        .unwrap_or_else(|e| panic!("Couldn't set up TLS for SMTP_HOST '{host}': {e}")),
+      "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+      tls => panic!("Unknown SMTP_TLS '{tls}', must be one of starttls, tls or none"),
+    };
+    if let Some(port) = *SMTP_PORT {
+      builder = builder.port(port);
+    }
+    if let (Some(username), Some(password)) = (SMTP_USERNAME.clone(), SMTP_PASSWORD.clone()) {
+      builder = builder.credentials(Credentials::new(username, password));
+    }
+
+    let from = SMTP_FROM
+      .as_deref()
+      .unwrap_or_else(|| panic!("SMTP_FROM is required when NOTIFIERS includes email"));
+    let from = from
+      .parse::<Mailbox>()
+      .unwrap_or_else(|e| panic!("Couldn't parse SMTP_FROM '{from}' as an address: {e}"));
+
+    let to = SMTP_TO
+      .as_deref()
+      .unwrap_or_else(|| panic!("SMTP_TO is required when NOTIFIERS includes email"))
+      .split(',')
+      .map(str::trim)
+      .filter(|to| !to.is_empty())
+      .map(|to| {
+        to.parse::<Mailbox>()
+          .unwrap_or_else(|e| panic!("Couldn't parse SMTP_TO address '{to}': {e}"))
+      })
+      .collect::<Vec<_>>();
+    assert!(!to.is_empty(), "SMTP_TO must have at least one address");
+
+    Self {
+      transport: builder.build(),
+      from,
+      to,
+    }
+  }
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+  fn name(&self) -> &'static str {
+    "email"
+  }
+
+  async fn notify(&self, notification: &Notification) -> Result<(), VoyagerError> {
+    event!(
+      Level::INFO,
+      "Sending {} notification email for deployment {}",
+      notification.event,
+      notification.deployment_id
+    );
+
+    let body = format!(
+      "{}\n\nID: {}\nDocker Container: {}\nURL: https://{}\n",
+      notification.description(),
+      notification.deployment_id,
+      notification.container_name,
+      notification.host
+    );
+
+    let mut message = Message::builder()
+      .from(self.from.clone())
+      .subject(format!("[Voyager] {}", notification.title()))
+      .header(ContentType::TEXT_PLAIN);
+    for to in &self.to {
+      message = message.to(to.clone());
+    }
+    let message = message
+      .body(body)
+      .map_err(|e| VoyagerError::build_notification_email(Box::new(e)))?;
+
+    self
+      .transport
+      .send(message)
+      .await
+      .map_err(|e| VoyagerError::send_notification_email(Box::new(e)))?;
+
+    event!(Level::DEBUG, "Done sending notification email.");
+
+    Ok(())
+  }
+}
+
+impl VoyagerError {
+  fn build_notification_email(e: Error) -> Self {
+    Self::new(
+      "Failed to build notification email".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      true,
+      Some(e),
+    )
+  }
+
+  fn send_notification_email(e: Error) -> Self {
+    Self::new(
+      "Failed to send notification email".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      true,
+      Some(e),
+    )
+  }
+}
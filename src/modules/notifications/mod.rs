@@ -0,0 +1,84 @@
+mod discord_notifier;
+mod email_notifier;
+mod webhook_notifier;
+
+pub use discord_notifier::*;
+pub use email_notifier::*;
+pub use webhook_notifier::*;
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use lazy_static::lazy_static;
+use tracing::{event, Level};
+
+use crate::{
+  configs::environment::{DISCORD_EVENTS, NOTIFIERS as NOTIFIER_NAMES, NOTIFY_WEBHOOK_EVENTS, SMTP_EVENTS},
+  types::other::{
+    notification::{Notification, NotificationEvent},
+    voyager_error::VoyagerError,
+  },
+};
+
+#[async_trait]
+pub trait Notifier: Sync + Send {
+  fn name(&self) -> &'static str;
+  async fn notify(&self, notification: &Notification) -> Result<(), VoyagerError>;
+}
+
+struct Channel {
+  notifier: Box<dyn Notifier>,
+  events: Vec<NotificationEvent>,
+}
+
+lazy_static! {
+  static ref CHANNELS: Vec<Channel> = NOTIFIER_NAMES
+    .split(',')
+    .map(str::trim)
+    .filter(|name| !name.is_empty())
+    .map(|name| match name {
+      "discord" => channel(Box::new(DiscordNotifier::from_environment()), DISCORD_EVENTS.as_deref()),
+      "webhook" => channel(Box::new(WebhookNotifier::from_environment()), NOTIFY_WEBHOOK_EVENTS.as_deref()),
+      "email" => channel(Box::new(EmailNotifier::from_environment()), SMTP_EVENTS.as_deref()),
+      name => panic!("Unknown notifier '{name}' in NOTIFIERS, must be among discord, webhook or email"),
+    })
+    .collect();
+}
+
+fn channel(notifier: Box<dyn Notifier>, events: Option<&str>) -> Channel {
+  let events = events.map_or_else(
+    || NotificationEvent::ALL.to_vec(),
+    |events| {
+      events
+        .split(',')
+        .map(str::trim)
+        .filter(|event| !event.is_empty())
+        .map(|event| event.parse().unwrap_or_else(|e| panic!("{e} for the {} notifier", notifier.name())))
+        .collect()
+    },
+  );
+
+  Channel { notifier, events }
+}
+
+// Sends the notification through every channel configured for its event, all at once.
+// Notifying is best-effort, so failures are only logged
+pub async fn notify(notification: Notification) {
+  let notification = &notification;
+  let sends = CHANNELS
+    .iter()
+    .filter(|channel| channel.events.contains(&notification.event))
+    .map(|channel| async move {
+      if let Err(e) = channel.notifier.notify(notification).await {
+        event!(
+          Level::WARN,
+          "Failed to send {} notification for deployment {} through {}: {}",
+          notification.event,
+          notification.deployment_id,
+          channel.notifier.name(),
+          e.message
+        );
+      }
+    });
+
+  join_all(sends).await;
+}
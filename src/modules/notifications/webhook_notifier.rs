@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use std::time::Duration;
+use tracing::{event, Level};
+
+use super::Notifier;
+use crate::{
+  configs::environment::NOTIFY_WEBHOOK_URL,
+  types::other::{notification::Notification, voyager_error::VoyagerError},
+  utils::Error,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// POSTs every notification as JSON to a URL, for integrations that aren't built in
+pub struct WebhookNotifier {
+  url: String,
+  client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+  pub fn from_environment() -> Self {
+    let url = NOTIFY_WEBHOOK_URL
+      .clone()
+      .unwrap_or_else(|| panic!("NOTIFY_WEBHOOK_URL is required when NOTIFIERS includes webhook"));
+    let client = reqwest::Client::builder()
+      .timeout(REQUEST_TIMEOUT)
+      .build()
+      .unwrap_or_else(|e| panic!("Couldn't create the notification webhook client: {e}"));
+
+    Self { url, client }
+  }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+  fn name(&self) -> &'static str {
+    "webhook"
+  }
+
+  async fn notify(&self, notification: &Notification) -> Result<(), VoyagerError> {
+    event!(
+      Level::INFO,
+      "Sending {} notification webhook for deployment {}",
+      notification.event,
+      notification.deployment_id
+    );
+
+    self
+      .client
+      .post(&self.url)
+      .json(notification)
+      .send()
+      .await
+      .and_then(reqwest::Response::error_for_status)
+      .map_err(|e| VoyagerError::notification_webhook(Box::new(e)))?;
+
+    event!(Level::DEBUG, "Done sending notification webhook.");
+
+    Ok(())
+  }
+}
+
+impl VoyagerError {
+  fn notification_webhook(e: Error) -> Self {
+    Self::new(
+      "Failed to send notification webhook".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      true,
+      Some(e),
+    )
+  }
+}
@@ -1,60 +1,162 @@
-use futures::FutureExt;
-use tokio::io::{self};
-use tokio::sync::Mutex;
-use tracing::{event, Level};
-use std::fs::File;
-use std::io::BufWriter;
-use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use bytes::Bytes;
+use futures::Stream;
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
 use tar::Builder;
+use tokio::sync::mpsc::{self, Sender};
+use tracing::{event, Level};
+
+use crate::types::other::ignore_pattern::IgnorePattern;
+
+// Docker reads the build context slower than the disk is read, so at most this many chunks wait in memory
+const CHUNK_SIZE: usize = 64 * 1024;
+const BUFFERED_CHUNKS: usize = 16;
 
-pub async fn create(folder_path: &Path) -> Result<PathBuf, io::Error> {
+// Streams a tarball of the folder as it is written, leaving out what the .dockerignore patterns exclude.
+// The archive is never whole on disk or in memory, and stops being written once the stream is dropped
+pub fn create(
+  folder_path: &Path,
+  ignore_patterns: Vec<IgnorePattern>,
+) -> impl Stream<Item = Result<Bytes, io::Error>> + Send + 'static {
   event!(Level::INFO, "Creating tarball from folder: {}", folder_path.display());
 
-  // Create a tarball at the specified location
-  let folder_path = folder_path.canonicalize()?;
-  #[allow(clippy::unwrap_used)] // Since we used canonicalize, this shouldn't panic
-  let mut tarball_name = folder_path.file_name().unwrap().to_os_string();
-  tarball_name.push(".tar.gz");
-  let tarball_path = folder_path.parent()
-    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid folder"))?
-    .join(tarball_name);
-  let tarball_file = File::create(&tarball_path)?;
+  let (sender, receiver) = mpsc::channel(BUFFERED_CHUNKS);
+  let folder_path = folder_path.to_path_buf();
+  tokio::task::spawn_blocking(move || {
+    let mut tar_writer = Builder::new(BufWriter::with_capacity(CHUNK_SIZE, ChannelWriter(sender.clone())));
+    // Symbolic links are archived as links, so that one can't send files from outside of the folder
+    tar_writer.follow_symlinks(false);
 
-  // Create a gzip encoder
-  // let gzip_encoder = flate2::write::GzEncoder::new(tarball_file, Compression::default());
+    let result = add_folder_contents(&mut tar_writer, &folder_path, Path::new(""), &ignore_patterns)
+      .and_then(|()| tar_writer.into_inner())
+      .and_then(|mut writer| writer.flush());
 
-  // Create a tar writer
-  let tar_writer = Builder::new(BufWriter::new(tarball_file));
-  let tar_writer = Arc::new(Mutex::new(tar_writer));
+    match result {
+      Ok(()) => event!(Level::DEBUG, "Finished creating tarball"),
+      Err(e) => {
+        event!(Level::WARN, "Failed to create tarball: {e}");
+        let _ = sender.blocking_send(Err(e));
+      },
+    }
+  });
 
-  // Iterate through the contents of the folder
-  add_folder_contents(tar_writer.clone(), folder_path).await?;
+  futures::stream::unfold(receiver, |mut receiver| async move {
+    receiver.recv().await.map(|chunk| (chunk, receiver))
+  })
+}
 
-  let _ = tar_writer.lock().await.finish();
+fn add_folder_contents<W: Write>(
+  tar_writer: &mut Builder<W>,
+  root: &Path,
+  folder: &Path,
+  ignore_patterns: &[IgnorePattern],
+) -> Result<(), io::Error> {
+  let mut entries = fs::read_dir(root.join(folder))?.collect::<Result<Vec<_>, _>>()?;
+  entries.sort_by_key(fs::DirEntry::file_name);
 
-  event!(Level::DEBUG, "Finished creating tarball");
+  for entry in entries {
+    let path = folder.join(entry.file_name());
+    let ignored = is_ignored(ignore_patterns, &path);
 
-  Ok(tarball_path)
+    if entry.file_type()?.is_dir() {
+      // An exception could still add back something inside of an excluded folder
+      if ignored && ignore_patterns.iter().all(|p| !p.exception) {
+        continue;
+      }
+      if !ignored {
+        tar_writer.append_dir(&path, entry.path())?;
+      }
+      add_folder_contents(tar_writer, root, &path, ignore_patterns)?;
+    } else if !ignored || is_always_sent(&path) {
+      tar_writer.append_path_with_name(entry.path(), &path)?;
+    }
+  }
+
+  Ok(())
 }
 
-fn add_folder_contents<W>(tar_writer: Arc<Mutex<Builder<W>>>, folder_path: PathBuf) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<(), io::Error>> + std::marker::Send>>
-where
-    W: std::marker::Unpin + std::io::Write + std::marker::Send + 'static,
-{
-    async move {
-      let mut dir = tokio::fs::read_dir(folder_path).await?;
-      while let Some(entry) = dir.next_entry().await? {
-        let path = entry.path();
-        let name = path.file_name().ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid file name"))?;
-
-        if path.is_file() {
-          let file = tokio::fs::File::open(&path).await?;
-          tar_writer.lock().await.append_file(name, &mut file.into_std().await)?;
-        } else if path.is_dir() {
-          tar_writer.lock().await.append_dir_all(name, &path)?;
-        }
-      }
-      Ok(())
-    }.boxed()
-}
\ No newline at end of file
+// The last pattern matching the path or one of its parent folders decides, as with the Docker CLI
+fn is_ignored(ignore_patterns: &[IgnorePattern], path: &Path) -> bool {
+  let paths = path
+    .ancestors()
+    .filter(|p| !p.as_os_str().is_empty())
+    .map(|p| p.to_string_lossy().into_owned())
+    .collect::<Vec<_>>();
+
+  ignore_patterns.iter().fold(false, |ignored, pattern| {
+    if paths.iter().any(|p| pattern.regex.is_match(p)) {
+      !pattern.exception
+    } else {
+      ignored
+    }
+  })
+}
+
+// Docker needs these even when they exclude themselves
+fn is_always_sent(path: &Path) -> bool {
+  path == Path::new("Dockerfile") || path == Path::new(".dockerignore")
+}
+
+struct ChannelWriter(Sender<Result<Bytes, io::Error>>);
+
+impl Write for ChannelWriter {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self
+      .0
+      .blocking_send(Ok(Bytes::copy_from_slice(buf)))
+      .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "The build context is no longer being read"))?;
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use super::{is_always_sent, is_ignored};
+  use crate::modules::tar::read_dockerignore::parse_dockerignore;
+
+  fn ignored(dockerignore: &str, path: &str) -> bool {
+    let patterns = parse_dockerignore(dockerignore).unwrap_or_else(|e| panic!("{}", e.message));
+    is_ignored(&patterns, Path::new(path))
+  }
+
+  #[test]
+  fn excluded_folders_exclude_their_contents() {
+    assert!(ignored("node_modules", "node_modules/a/index.js"));
+    assert!(ignored("/build/", "build/out.o"));
+    assert!(!ignored("node_modules", "src/node_modules.rs"));
+  }
+
+  #[test]
+  fn exceptions_re_include_inside_of_excluded_folders() {
+    let dockerignore = "docs\n!docs/README.md\n";
+    assert!(ignored(dockerignore, "docs/guide.md"));
+    assert!(!ignored(dockerignore, "docs/README.md"));
+  }
+
+  #[test]
+  fn last_matching_pattern_wins() {
+    assert!(ignored("!docs/README.md\ndocs", "docs/README.md"));
+    assert!(!ignored("*.md\n!README.md", "README.md"));
+    assert!(ignored("*.md\n!README.md\nREADME.md", "README.md"));
+  }
+
+  #[test]
+  fn comments_and_blank_lines_are_skipped() {
+    assert!(!ignored("# secret\n\n", "secret"));
+    assert!(ignored("# comment\n  secret  \n", "secret"));
+  }
+
+  #[test]
+  fn dockerfile_and_dockerignore_are_always_sent() {
+    assert!(is_always_sent(Path::new("Dockerfile")));
+    assert!(is_always_sent(Path::new(".dockerignore")));
+    assert!(!is_always_sent(Path::new("app/Dockerfile")));
+  }
+}
@@ -1,3 +1,5 @@
 mod create;
+mod read_dockerignore;
 
 pub use create::*;
+pub use read_dockerignore::*;
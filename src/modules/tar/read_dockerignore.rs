@@ -0,0 +1,180 @@
+use axum::http::StatusCode;
+use regex::Regex;
+use std::{io, path::Path};
+use tracing::{event, Level};
+
+use crate::{
+  types::other::{ignore_pattern::IgnorePattern, voyager_error::VoyagerError},
+  utils::Error,
+};
+
+// Reads the patterns of the .dockerignore at the root of the build context, none if there is no such file.
+// They follow the Docker CLI's rules, so a context builds the same here as with `docker build`
+pub fn read_dockerignore(folder_path: &Path) -> Result<Vec<IgnorePattern>, VoyagerError> {
+  let contents = match std::fs::read_to_string(folder_path.join(".dockerignore")) {
+    Ok(contents) => contents,
+    Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+    Err(e) => return Err(VoyagerError::read_dockerignore(Box::new(e))),
+  };
+
+  let patterns = parse_dockerignore(&contents)?;
+
+  event!(Level::DEBUG, "Read {} .dockerignore patterns.", patterns.len());
+
+  Ok(patterns)
+}
+
+pub(super) fn parse_dockerignore(contents: &str) -> Result<Vec<IgnorePattern>, VoyagerError> {
+  contents
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    .filter_map(|line| {
+      let (exception, pattern) = line.strip_prefix('!').map_or((false, line), |p| (true, p.trim()));
+      let pattern = clean(pattern);
+      // "." and "/" name the context itself, which is never excluded
+      (!pattern.is_empty()).then(|| {
+        to_regex(&pattern)
+          .map(|regex| IgnorePattern { regex, exception })
+          .map_err(|e| VoyagerError::invalid_dockerignore(Box::new(e), line))
+      })
+    })
+    .collect()
+}
+
+// Like Go's filepath.Clean, which Docker applies to every pattern, and without the leading slash
+fn clean(pattern: &str) -> String {
+  pattern
+    .split('/')
+    .fold(Vec::new(), |mut segments, segment| {
+      match segment {
+        "" | "." => {},
+        ".." => {
+          segments.pop();
+        },
+        segment => segments.push(segment),
+      }
+      segments
+    })
+    .join("/")
+}
+
+// `*` and `?` don't cross directories, `**` matches any number of them, `[...]` is a character class and
+// `\` escapes the next character
+fn to_regex(pattern: &str) -> Result<Regex, regex::Error> {
+  let mut regex = String::from("^");
+  let mut chars = pattern.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+        // "**/" also matches no directory at all
+        if chars.peek() == Some(&'/') {
+          chars.next();
+        }
+        regex.push_str(if chars.peek().is_some() { "(.*/)?" } else { ".*" });
+      },
+      '*' => regex.push_str("[^/]*"),
+      '?' => regex.push_str("[^/]"),
+      '\\' => {
+        if let Some(c) = chars.next() {
+          regex.push_str(&regex::escape(&c.to_string()));
+        }
+      },
+      '[' => {
+        regex.push('[');
+        if chars.next_if(|c| *c == '!' || *c == '^').is_some() {
+          regex.push('^');
+        }
+        for c in chars.by_ref() {
+          regex.push(c);
+          if c == ']' {
+            break;
+          }
+        }
+      },
+      c => regex.push_str(&regex::escape(&c.to_string())),
+    }
+  }
+  regex.push('$');
+
+  Regex::new(&regex)
+}
+
+impl VoyagerError {
+  fn read_dockerignore(e: Error) -> Self {
+    Self::new(
+      "Failed to read .dockerignore".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      Some(e),
+    )
+  }
+
+  fn invalid_dockerignore(e: Error, line: &str) -> Self {
+    Self::new(
+      format!("Invalid pattern '{line}' in .dockerignore"),
+      StatusCode::BAD_REQUEST,
+      false,
+      Some(e),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{clean, to_regex};
+
+  fn matches(pattern: &str, path: &str) -> bool {
+    to_regex(&clean(pattern))
+      .unwrap_or_else(|e| panic!("Invalid pattern {pattern}: {e}"))
+      .is_match(path)
+  }
+
+  #[test]
+  fn clean_resolves_dots_and_drops_leading_slash() {
+    assert_eq!(clean("/a/./b//c/../d/"), "a/b/d");
+    assert_eq!(clean("."), "");
+    assert_eq!(clean("/"), "");
+  }
+
+  #[test]
+  fn single_star_and_question_mark_stay_in_one_folder() {
+    assert!(matches("*.md", "README.md"));
+    assert!(!matches("*.md", "docs/README.md"));
+    assert!(matches("a?c", "abc"));
+    assert!(!matches("a?c", "a/c"));
+  }
+
+  #[test]
+  fn leading_double_star_matches_any_number_of_folders() {
+    assert!(matches("**/x", "x"));
+    assert!(matches("**/x", "a/x"));
+    assert!(matches("**/x", "a/b/x"));
+    assert!(!matches("**/x", "a/xy"));
+  }
+
+  #[test]
+  fn trailing_double_star_matches_everything_inside() {
+    assert!(matches("dir/**", "dir/a"));
+    assert!(matches("dir/**", "dir/a/b"));
+    assert!(!matches("dir/**", "other/a"));
+  }
+
+  #[test]
+  fn character_classes_can_be_negated() {
+    assert!(matches("[ab]c", "bc"));
+    assert!(!matches("[!a]c", "ac"));
+    assert!(matches("[!a]c", "bc"));
+    assert!(matches("[^a]c", "bc"));
+  }
+
+  #[test]
+  fn backslash_escapes_special_characters() {
+    assert!(matches(r"a\*b", "a*b"));
+    assert!(!matches(r"a\*b", "axb"));
+    assert!(matches(r"\[a]", "[a]"));
+    assert!(matches("a.b", "a.b"));
+    assert!(!matches("a.b", "axb"));
+  }
+}
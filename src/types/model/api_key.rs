@@ -0,0 +1,47 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApiKeyScope {
+  // Listing and reading deployments, their builds, logs and environment
+  Read,
+  // Creating and changing preview deployments
+  DeployPreview,
+  // Creating and changing production deployments
+  DeployProduction,
+  // Deleting deployments
+  Delete,
+  // Everything, including managing API keys
+  Admin,
+}
+
+impl fmt::Display for ApiKeyScope {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Read => write!(f, "read"),
+      Self::DeployPreview => write!(f, "deploy-preview"),
+      Self::DeployProduction => write!(f, "deploy-production"),
+      Self::Delete => write!(f, "delete"),
+      Self::Admin => write!(f, "admin"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+  #[serde(rename = "_id")]
+  pub id: ObjectId,
+  pub name: String,
+  // Hex encoded SHA-256 of the key, which is only shown once when created
+  pub key_hash: String,
+  pub scopes: Vec<ApiKeyScope>,
+  // Unix timestamps, in seconds
+  pub created_at: i64,
+  // None for keys that never expire
+  pub expires_at: Option<i64>,
+  pub last_used_at: Option<i64>,
+  pub revoked_at: Option<i64>,
+}
@@ -0,0 +1,41 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+// A mutating API call, recorded once it has been answered
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLog {
+  #[serde(rename = "_id")]
+  #[schema(value_type = Object, example = json!({"$oid": "65f1c0ffee0ddba11c0ffee0"}))]
+  pub id: ObjectId,
+  // None for the API_KEY of the environment and for webhooks
+  pub api_key_id: Option<String>,
+  pub api_key_name: String,
+  // e.g. deployment.create, deployment.delete or environment.set
+  pub action: String,
+  // Id of the deployment or API key acted upon, when there is one
+  pub target_id: Option<String>,
+  pub method: String,
+  pub path: String,
+  pub params: AuditParams,
+  pub outcome: AuditOutcome,
+  // Unix timestamp, in seconds
+  pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AuditParams {
+  pub path: HashMap<String, String>,
+  pub query: HashMap<String, String>,
+  // JSON bodies only, with the values of environment variables redacted
+  #[schema(value_type = Option<Object>)]
+  pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditOutcome {
+  pub status: u16,
+  pub succeeded: bool,
+  pub errors: Vec<String>,
+}
@@ -0,0 +1,54 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use std::fmt;
+
+use super::deployment::Commit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum BuildOutcome {
+  Succeeded,
+  Failed,
+}
+
+impl fmt::Display for BuildOutcome {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Succeeded => write!(f, "Succeeded"),
+      Self::Failed => write!(f, "Failed"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BuildStep {
+  pub name: String,
+  pub duration_ms: i64,
+  pub succeeded: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Build {
+  #[serde(rename = "_id")]
+  #[schema(value_type = Object, example = json!({"$oid": "65f1c0ffee0ddba11c0ffee0"}))]
+  pub id: ObjectId,
+  #[schema(value_type = Object, example = json!({"$oid": "65f1c0ffee0ddba11c0ffee0"}))]
+  pub deployment_id: ObjectId,
+  // Unknown for builds recorded before commits were, including the older ones that only had a commit_sha
+  #[serde(default)]
+  pub commit: Option<Commit>,
+  // Unset once the image is deleted, past the IMAGE_RETENTION latest builds
+  pub image_id: Option<String>,
+  // Set for rollbacks, which reuse the image of that build instead of building one
+  #[serde(default)]
+  #[schema(value_type = Option<Object>)]
+  pub rolled_back_from: Option<ObjectId>,
+  pub outcome: BuildOutcome,
+  pub error: Option<String>,
+  pub steps: Vec<BuildStep>,
+  // Left out when listing builds, as it holds up to the last 4MB of the build output
+  #[serde(default)]
+  pub output: Vec<String>,
+  pub started_at: i64,
+  pub finished_at: i64,
+}
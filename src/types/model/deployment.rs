@@ -1,8 +1,9 @@
 use mongodb::bson::{self};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use utoipa::ToSchema;
+use std::{collections::HashMap, fmt};
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
 pub enum Mode {
   Preview,
   Production,
@@ -17,16 +18,108 @@ impl fmt::Display for Mode {
   }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum DeploymentState {
+  Queued,
+  Cloning,
+  Building,
+  Starting,
+  Deployed,
+  Failed,
+  Stopped,
+  Deleting,
+}
+
+impl fmt::Display for DeploymentState {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Queued => write!(f, "Queued"),
+      Self::Cloning => write!(f, "Cloning"),
+      Self::Building => write!(f, "Building"),
+      Self::Starting => write!(f, "Starting"),
+      Self::Deployed => write!(f, "Deployed"),
+      Self::Failed => write!(f, "Failed"),
+      Self::Stopped => write!(f, "Stopped"),
+      Self::Deleting => write!(f, "Deleting"),
+    }
+  }
+}
+
+// Deployments saved before states were tracked only existed once fully deployed
+const fn default_state() -> DeploymentState {
+  DeploymentState::Deployed
+}
+
+// How the image is built, kept so that redeploys build it the same way
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct BuildOptions {
+  #[serde(default)]
+  pub build_args: HashMap<String, String>,
+  // Stage of a multi-stage Dockerfile to build, instead of the last one
+  #[serde(default)]
+  pub target: Option<String>,
+  #[serde(default)]
+  pub no_cache: bool,
+}
+
+// Limits of the running container. Unset ones fall back to the defaults of the deployment's mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ResourceLimits {
+  #[serde(default)]
+  pub cpus: Option<f64>,
+  #[serde(default)]
+  pub memory_mb: Option<i64>,
+  // Memory plus swap, -1 for unlimited swap
+  #[serde(default)]
+  pub memory_swap_mb: Option<i64>,
+  // -1 for unlimited
+  #[serde(default)]
+  pub pids: Option<i64>,
+}
+
+// The commit a deployment was last built from
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Commit {
+  pub sha: String,
+  // As "Name <email>"
+  pub author: String,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Deployment {
-  pub _id: bson::oid::ObjectId,
-  pub container_id: String,
-  pub dns_record_id: String,
+  #[serde(rename = "_id")]
+  #[schema(value_type = Object, example = json!({"$oid": "65f1c0ffee0ddba11c0ffee0"}))]
+  pub id: bson::oid::ObjectId,
+  // These are only known once the corresponding step of the deployment has run
+  pub container_id: Option<String>,
+  pub dns_record_id: Option<String>,
   pub container_name: String,
-  pub image_id: String,
+  pub image_id: Option<String>,
   // pub port: u16,
   pub mode: Mode,
   pub host: String,
   pub repo_url: String,
   pub branch: String,
+  // Commit SHA or tag the deployment is pinned to, instead of the head of its branch
+  #[serde(default)]
+  pub git_ref: Option<String>,
+  #[serde(default)]
+  pub commit: Option<Commit>,
+  #[serde(default = "default_state")]
+  pub state: DeploymentState,
+  #[serde(default)]
+  pub failure_reason: Option<String>,
+  #[serde(default)]
+  pub build_options: BuildOptions,
+  #[serde(default)]
+  pub resource_limits: ResourceLimits,
+  // Unix timestamps, in seconds
+  #[serde(default)]
+  pub created_at: i64,
+  #[serde(default)]
+  pub updated_at: i64,
+  // None for deployments that never expire
+  #[serde(default)]
+  pub expires_at: Option<i64>,
 }
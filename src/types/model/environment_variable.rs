@@ -0,0 +1,14 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentVariable {
+  #[serde(rename = "_id")]
+  pub id: ObjectId,
+  pub deployment_id: ObjectId,
+  pub name: String,
+  // Encrypted when the variable is a secret
+  pub value: String,
+  pub secret: bool,
+  pub updated_at: i64,
+}
@@ -0,0 +1,42 @@
+use mongodb::bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum GitCredentialKind {
+  // A username and personal access token, for HTTPS remotes
+  Token,
+  // A private key, for SSH remotes
+  SshKey,
+}
+
+impl fmt::Display for GitCredentialKind {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Token => write!(f, "token"),
+      Self::SshKey => write!(f, "ssh-key"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitCredential {
+  #[serde(rename = "_id")]
+  pub id: ObjectId,
+  pub name: String,
+  // Lowercase, without the port, e.g. github.com
+  pub host: String,
+  // Lowercase organisation or user the credential is restricted to. None for every repository of the host
+  pub owner: Option<String>,
+  pub kind: GitCredentialKind,
+  // Only used by tokens, SSH keys use the user of the URL
+  pub username: Option<String>,
+  // Token or private key, encrypted with SECRETS_KEY
+  pub secret: String,
+  // Passphrase of the private key, encrypted as well
+  pub passphrase: Option<String>,
+  // Unix timestamp, in seconds
+  pub created_at: i64,
+}
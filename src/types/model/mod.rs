@@ -1 +1,6 @@
+pub mod api_key;
+pub mod audit_log;
+pub mod build;
 pub mod deployment;
+pub mod environment_variable;
+pub mod git_credential;
@@ -0,0 +1,23 @@
+use crate::types::model::{api_key::ApiKeyScope, deployment::Mode};
+
+// Who is calling the API, added to the request extensions by the authorization middleware
+#[derive(Debug, Clone)]
+pub struct ApiKeyIdentity {
+  // None for the API_KEY of the environment
+  pub id: Option<String>,
+  pub name: String,
+  pub scopes: Vec<ApiKeyScope>,
+}
+
+impl ApiKeyIdentity {
+  pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+    self.scopes.contains(&scope) || self.scopes.contains(&ApiKeyScope::Admin)
+  }
+
+  pub fn can_deploy(&self, mode: Mode) -> bool {
+    self.has_scope(match mode {
+      Mode::Preview => ApiKeyScope::DeployPreview,
+      Mode::Production => ApiKeyScope::DeployProduction,
+    })
+  }
+}
@@ -0,0 +1,11 @@
+// Every field that is set must match
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+  pub api_key_id: Option<String>,
+  pub action: Option<String>,
+  pub target_id: Option<String>,
+  // Unix timestamps, in seconds, both inclusive
+  pub since: Option<i64>,
+  pub until: Option<i64>,
+  pub limit: Option<i64>,
+}
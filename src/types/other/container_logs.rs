@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use std::fmt;
+
+// Where a container log line was written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LogSource {
+  Stdout,
+  Stderr,
+  // Containers with a TTY attached don't tell both apart
+  Console,
+}
+
+impl fmt::Display for LogSource {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Stdout => write!(f, "stdout"),
+      Self::Stderr => write!(f, "stderr"),
+      Self::Console => write!(f, "console"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ContainerLog {
+  pub source: LogSource,
+  pub message: String,
+}
+
+// Which container logs to retrieve. None means no limit
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ContainerLogsOptions {
+  pub tail: Option<u64>,
+  pub since: Option<i64>,
+  pub until: Option<i64>,
+  pub timestamps: bool,
+}
@@ -0,0 +1,6 @@
+// Decrypted credentials to clone a repository with
+#[derive(Clone)]
+pub enum GitAuth {
+  Token { username: String, token: String },
+  SshKey { private_key: String, passphrase: Option<String> },
+}
@@ -0,0 +1,15 @@
+// A repository to clone, as given when creating a deployment
+#[derive(Debug, Clone)]
+pub struct GitRemote {
+  // What git clones from
+  pub url: String,
+  // Lowercase, without the port
+  pub host: String,
+  // Lowercase organisation or user owning the repository, the first segment of its path
+  pub owner: String,
+  // Lowercase path of the repository, without slashes around it nor the .git suffix
+  pub path: String,
+  pub ssh: bool,
+  // Plain HTTP, which credentials are never sent over
+  pub insecure: bool,
+}
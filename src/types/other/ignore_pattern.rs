@@ -0,0 +1,9 @@
+use regex::Regex;
+
+// A line of a .dockerignore, matched against paths relative to the build context
+#[derive(Debug, Clone)]
+pub struct IgnorePattern {
+  pub regex: Regex,
+  // Lines starting with '!', which add back what earlier lines excluded
+  pub exception: bool,
+}
@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio::sync::broadcast;
+
+const CHANNEL_CAPACITY: usize = 1024;
+
+// Collects output lines, and broadcasts them to whoever is following along.
+// Followers always get every line already pushed before the ones that come next.
+#[derive(Clone)]
+pub struct LogStream {
+  lines: Arc<Mutex<Vec<String>>>,
+  sender: broadcast::Sender<String>,
+}
+
+impl LogStream {
+  pub fn new() -> Self {
+    let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+
+    Self {
+      lines: Arc::new(Mutex::new(Vec::new())),
+      sender,
+    }
+  }
+
+  pub fn push(&self, line: impl Into<String>) {
+    let line = line.into();
+    let mut lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+
+    // Sending fails only when nobody is following, which is fine
+    let _ = self.sender.send(line.clone());
+    lines.push(line);
+  }
+
+  // Pushes every non-empty line of a chunk of output. Progress bars redraw themselves with carriage returns,
+  // which also end a line, as Server-Sent Events can't carry them
+  pub fn push_chunk(&self, chunk: &str) {
+    chunk
+      .split(['\r', '\n'])
+      .map(str::trim_end)
+      .filter(|l| !l.is_empty())
+      .for_each(|l| self.push(l));
+  }
+
+  // The last lines fitting in max_bytes, after a line telling how many were left out before them
+  pub fn tail(&self, max_bytes: usize) -> Vec<String> {
+    let lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let mut size = 0;
+    let kept = lines
+      .iter()
+      .rev()
+      .take_while(|line| {
+        size += line.len();
+        size <= max_bytes
+      })
+      .count();
+    let skipped = lines.len() - kept;
+
+    let mut tail = Vec::with_capacity(kept + 1);
+    if skipped > 0 {
+      tail.push(format!("[{skipped} earlier lines left out]"));
+    }
+    tail.extend_from_slice(&lines[skipped..]);
+    tail
+  }
+
+  pub fn subscribe(&self) -> (Vec<String>, broadcast::Receiver<String>) {
+    let lines = self.lines.lock().unwrap_or_else(PoisonError::into_inner);
+
+    (lines.clone(), self.sender.subscribe())
+  }
+}
+
+impl Default for LogStream {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::LogStream;
+
+  #[test]
+  fn push_chunk_splits_on_carriage_returns() {
+    let stream = LogStream::new();
+    stream.push_chunk("Step 1/2 : FROM node\r\n#5 10%\r#5 50%\r#5 100%\n\n");
+
+    assert_eq!(stream.tail(usize::MAX), ["Step 1/2 : FROM node", "#5 10%", "#5 50%", "#5 100%"]);
+  }
+
+  #[test]
+  fn tail_keeps_the_last_lines() {
+    let stream = LogStream::new();
+    for line in ["one", "two", "three", "four"] {
+      stream.push(line);
+    }
+
+    assert_eq!(stream.tail(9), ["[2 earlier lines left out]", "three", "four"]);
+    assert_eq!(stream.tail(100), ["one", "two", "three", "four"]);
+  }
+}
@@ -1 +1,10 @@
+pub mod api_key_identity;
+pub mod audit_log_filter;
+pub mod container_logs;
+pub mod git_auth;
+pub mod git_remote;
+pub mod ignore_pattern;
+pub mod log_stream;
+pub mod new_deployment;
+pub mod notification;
 pub mod voyager_error;
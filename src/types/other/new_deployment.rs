@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::types::model::deployment::{BuildOptions, Mode, ResourceLimits};
+
+// A validated deployment creation request
+#[derive(Debug, Clone)]
+pub struct NewDeployment {
+  pub host: String,
+  pub mode: Mode,
+  pub repo_url: String,
+  pub branch: Option<String>,
+  pub git_ref: Option<String>,
+  pub environment: Vec<NewEnvironmentVariable>,
+  pub build_options: BuildOptions,
+  pub resource_limits: ResourceLimits,
+  // Seconds before the deployment expires, see services::deployments::extend
+  pub ttl: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NewEnvironmentVariable {
+  pub name: String,
+  pub value: String,
+  #[serde(default)]
+  pub secret: bool,
+}
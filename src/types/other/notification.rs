@@ -0,0 +1,101 @@
+use serde::Serialize;
+use std::{fmt, str::FromStr};
+
+use crate::types::model::deployment::{Deployment, Mode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotificationEvent {
+  // A new deployment is up and running
+  Created,
+  // A deployment was redeployed with a new build, or rolled back to an older one
+  Redeployed,
+  // Deploying or redeploying failed
+  Failed,
+  // A deployment was deleted, through the API or once expired
+  Deleted,
+  // A container stopped running and couldn't be restarted
+  Down,
+}
+
+impl NotificationEvent {
+  pub const ALL: [Self; 5] = [Self::Created, Self::Redeployed, Self::Failed, Self::Deleted, Self::Down];
+}
+
+impl fmt::Display for NotificationEvent {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Self::Created => write!(f, "created"),
+      Self::Redeployed => write!(f, "redeployed"),
+      Self::Failed => write!(f, "failed"),
+      Self::Deleted => write!(f, "deleted"),
+      Self::Down => write!(f, "down"),
+    }
+  }
+}
+
+impl FromStr for NotificationEvent {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::ALL
+      .into_iter()
+      .find(|event| event.to_string() == s)
+      .ok_or_else(|| format!("Unknown notification event '{s}'"))
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+  pub event: NotificationEvent,
+  pub deployment_id: String,
+  pub container_name: String,
+  pub host: String,
+  pub mode: Mode,
+  // Why the deployment failed or went down
+  pub reason: Option<String>,
+  // Unix timestamp, in seconds
+  pub created_at: i64,
+}
+
+impl Notification {
+  pub fn new(event: NotificationEvent, deployment: &Deployment, reason: Option<String>) -> Self {
+    Self {
+      event,
+      deployment_id: deployment.id.to_hex(),
+      container_name: deployment.container_name.clone(),
+      host: deployment.host.clone(),
+      mode: deployment.mode,
+      reason,
+      created_at: chrono::Utc::now().timestamp(),
+    }
+  }
+
+  pub fn title(&self) -> String {
+    let Self { mode, container_name, .. } = self;
+    match self.event {
+      NotificationEvent::Created => format!("New {mode} deployment | {container_name}"),
+      NotificationEvent::Redeployed => format!("{mode} deployment redeployed | {container_name}"),
+      NotificationEvent::Failed => format!("{mode} deployment failed | {container_name}"),
+      NotificationEvent::Deleted => format!("{mode} deployment deleted | {container_name}"),
+      NotificationEvent::Down => format!("{mode} deployment down | {container_name}"),
+    }
+  }
+
+  pub fn description(&self) -> String {
+    let mode = self.mode;
+    let mut description = match self.event {
+      NotificationEvent::Created => format!("A new {mode} deployment has been created."),
+      NotificationEvent::Redeployed => format!("A {mode} deployment has been redeployed."),
+      NotificationEvent::Failed => format!("A {mode} deployment has failed to deploy."),
+      NotificationEvent::Deleted => format!("A {mode} deployment has been deleted."),
+      NotificationEvent::Down => format!("A {mode} deployment has stopped running and could not be restarted."),
+    };
+
+    if let Some(reason) = &self.reason {
+      description = format!("{description} Reason: {reason}");
+    }
+    description
+  }
+}
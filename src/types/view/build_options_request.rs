@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::types::model::deployment::BuildOptions;
+
+// Request bodies are camelCase like the query parameters, while BuildOptions keeps the names it is stored with
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildOptionsRequest {
+  #[serde(default)]
+  pub build_args: HashMap<String, String>,
+  // Stage of a multi-stage Dockerfile to build, instead of the last one
+  #[serde(default)]
+  pub target: Option<String>,
+  #[serde(default)]
+  pub no_cache: bool,
+}
+
+impl From<BuildOptionsRequest> for BuildOptions {
+  fn from(request: BuildOptionsRequest) -> Self {
+    Self {
+      build_args: request.build_args,
+      target: request.target,
+      no_cache: request.no_cache,
+    }
+  }
+}
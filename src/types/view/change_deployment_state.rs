@@ -0,0 +1,11 @@
+use crate::types::model::deployment::DeploymentState;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::logs::Logs;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ChangeDeploymentState {
+  pub logs: Logs,
+  pub state: Option<DeploymentState>,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{get_api_keys::ApiKeyInfo, logs::Logs};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateApiKey {
+  pub logs: Logs,
+  // Only ever sent here, as only its hash is stored
+  pub key: Option<String>,
+  pub api_key: Option<ApiKeyInfo>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::types::model::api_key::ApiKeyScope;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateApiKeyRequest {
+  pub name: String,
+  pub scopes: Vec<ApiKeyScope>,
+  // Seconds until the key expires, or never when left out
+  #[serde(default)]
+  pub ttl: Option<i64>,
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
-use super::logs::Logs;
+use utoipa::ToSchema;
+use super::{field_error::FieldError, logs::Logs};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateDeployment {
   pub logs: Logs,
   pub id: Option<String>,
-}
\ No newline at end of file
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub validation_errors: Vec<FieldError>,
+}
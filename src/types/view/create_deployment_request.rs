@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{build_options_request::BuildOptionsRequest, resource_limits_request::ResourceLimitsRequest};
+use crate::types::other::new_deployment::NewEnvironmentVariable;
+
+// Every field is validated separately, so that all errors can be reported at once
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateDeploymentRequest {
+  pub repo: Option<String>,
+  #[serde(default)]
+  pub branch: Option<String>,
+  // Commit SHA or tag to deploy, looked up in the cloned branch
+  #[serde(default, rename = "ref")]
+  pub git_ref: Option<String>,
+  #[serde(default)]
+  pub subdomain: Option<String>,
+  // One of the configured domains, defaults to the first one
+  #[serde(default)]
+  pub domain: Option<String>,
+  pub mode: Option<String>,
+  #[serde(default)]
+  pub env: Vec<NewEnvironmentVariable>,
+  #[serde(default)]
+  pub resources: ResourceLimitsRequest,
+  #[serde(default)]
+  pub build: BuildOptionsRequest,
+  #[serde(default)]
+  pub ttl: Option<i64>,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{get_git_credentials::GitCredentialInfo, logs::Logs};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateGitCredential {
+  pub logs: Logs,
+  pub git_credential: Option<GitCredentialInfo>,
+}
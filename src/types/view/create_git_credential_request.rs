@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::types::model::git_credential::GitCredentialKind;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateGitCredentialRequest {
+  pub name: String,
+  // Host name only, e.g. github.com
+  pub host: String,
+  // Restricts the credential to an organisation or user, instead of every repository of the host
+  #[serde(default)]
+  pub owner: Option<String>,
+  pub kind: GitCredentialKind,
+  // Required for tokens
+  #[serde(default)]
+  pub username: Option<String>,
+  // The token, or the private key
+  pub secret: String,
+  // Passphrase of the private key, if it has one
+  #[serde(default)]
+  pub passphrase: Option<String>,
+}
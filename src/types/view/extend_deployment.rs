@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use super::logs::Logs;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ExtendDeployment {
+  pub logs: Logs,
+  pub expires_at: Option<i64>,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use super::logs::Logs;
+
+// Only sent when the logs cannot be followed, as they are otherwise streamed as Server-Sent Events
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FollowBuildLogs {
+  pub logs: Logs,
+}
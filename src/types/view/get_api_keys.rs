@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::logs::Logs;
+use crate::types::model::api_key::{ApiKey, ApiKeyScope};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetApiKeys {
+  pub logs: Logs,
+  pub api_keys: Vec<ApiKeyInfo>,
+}
+
+// Key hashes are never sent back
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiKeyInfo {
+  pub id: String,
+  pub name: String,
+  pub scopes: Vec<ApiKeyScope>,
+  pub created_at: i64,
+  pub expires_at: Option<i64>,
+  pub last_used_at: Option<i64>,
+  pub revoked_at: Option<i64>,
+}
+
+impl From<ApiKey> for ApiKeyInfo {
+  fn from(api_key: ApiKey) -> Self {
+    Self {
+      id: api_key.id.to_hex(),
+      name: api_key.name,
+      scopes: api_key.scopes,
+      created_at: api_key.created_at,
+      expires_at: api_key.expires_at,
+      last_used_at: api_key.last_used_at,
+      revoked_at: api_key.revoked_at,
+    }
+  }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::logs::Logs;
+use crate::types::model::audit_log::AuditLog;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetAuditLogs {
+  pub logs: Logs,
+  pub audit_logs: Vec<AuditLog>,
+}
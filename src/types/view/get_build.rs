@@ -0,0 +1,11 @@
+use crate::types::model::build::Build;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::logs::Logs;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetBuild {
+  pub logs: Logs,
+  pub build: Option<Build>,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use super::logs::Logs;
+use crate::types::model::build::Build;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetBuilds {
+  pub logs: Logs,
+  pub builds: Vec<Build>
+}
@@ -1,9 +1,10 @@
 use crate::types::model::deployment::Deployment;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use super::logs::Logs;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GetDeployment {
   pub logs: Logs,
   pub deployment: Option<Deployment>,
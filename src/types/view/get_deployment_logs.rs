@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use super::logs::Logs;
+use crate::types::other::container_logs::ContainerLog;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GetDeploymentLogs {
   pub logs: Logs,
   #[serde(rename = "deploymentLogs")]
-  pub deployment_logs: Option<Vec<String>>,
-}
\ No newline at end of file
+  pub deployment_logs: Option<Vec<ContainerLog>>,
+}
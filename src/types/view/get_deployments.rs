@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use super::logs::Logs;
 use crate::types::model::deployment::Deployment;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GetDeployments {
   pub logs: Logs,
   pub deployments: Vec<Deployment>
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use super::logs::Logs;
+use crate::types::model::environment_variable::EnvironmentVariable;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetEnvironment {
+  pub logs: Logs,
+  pub environment: Vec<EnvironmentVariableInfo>,
+}
+
+// Secret values are never sent back
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EnvironmentVariableInfo {
+  pub name: String,
+  pub value: Option<String>,
+  pub secret: bool,
+  pub updated_at: i64,
+}
+
+impl From<EnvironmentVariable> for EnvironmentVariableInfo {
+  fn from(variable: EnvironmentVariable) -> Self {
+    Self {
+      name: variable.name,
+      value: (!variable.secret).then_some(variable.value),
+      secret: variable.secret,
+      updated_at: variable.updated_at,
+    }
+  }
+}
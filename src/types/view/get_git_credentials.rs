@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::logs::Logs;
+use crate::types::model::git_credential::{GitCredential, GitCredentialKind};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetGitCredentials {
+  pub logs: Logs,
+  pub git_credentials: Vec<GitCredentialInfo>,
+}
+
+// Secrets are never sent back
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GitCredentialInfo {
+  pub id: String,
+  pub name: String,
+  pub host: String,
+  pub owner: Option<String>,
+  pub kind: GitCredentialKind,
+  pub username: Option<String>,
+  pub created_at: i64,
+}
+
+impl From<GitCredential> for GitCredentialInfo {
+  fn from(git_credential: GitCredential) -> Self {
+    Self {
+      id: git_credential.id.to_hex(),
+      name: git_credential.name,
+      host: git_credential.host,
+      owner: git_credential.owner,
+      kind: git_credential.kind,
+      username: git_credential.username,
+      created_at: git_credential.created_at,
+    }
+  }
+}
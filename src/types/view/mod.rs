@@ -1,6 +1,33 @@
+pub mod build_options_request;
+pub mod change_deployment_state;
+pub mod create_api_key;
+pub mod create_api_key_request;
 pub mod create_deployment;
+pub mod create_deployment_request;
+pub mod create_git_credential;
+pub mod create_git_credential_request;
 pub mod delete_deployment;
+pub mod delete_git_credential;
+pub mod extend_deployment;
+pub mod field_error;
+pub mod follow_build_logs;
+pub mod get_api_keys;
+pub mod get_audit_logs;
+pub mod get_build;
+pub mod get_builds;
 pub mod get_deployment_logs;
 pub mod get_deployment;
 pub mod get_deployments;
-pub mod logs;
\ No newline at end of file
+pub mod get_environment;
+pub mod get_git_credentials;
+pub mod logs;
+pub mod push_event;
+pub mod push_webhook;
+pub mod redeploy_deployment;
+pub mod resource_limits_request;
+pub mod revoke_api_key;
+pub mod rollback_deployment;
+pub mod set_environment_variable;
+pub mod update_build_options;
+pub mod update_resource_limits;
+pub mod update_environment;
\ No newline at end of file
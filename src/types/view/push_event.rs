@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// Subset of the push event payload shared by GitHub and Gitea
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PushEvent {
+  #[serde(rename = "ref")]
+  pub git_ref: String,
+  pub repository: PushRepository,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PushRepository {
+  pub full_name: String,
+  pub default_branch: Option<String>,
+  #[serde(default)]
+  pub clone_url: Option<String>,
+  #[serde(default)]
+  pub ssh_url: Option<String>,
+  #[serde(default)]
+  pub html_url: Option<String>,
+}
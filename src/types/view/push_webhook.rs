@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use super::logs::Logs;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PushWebhook {
+  pub logs: Logs,
+  pub deployments: Vec<String>,
+}
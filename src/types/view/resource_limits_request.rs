@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::types::model::deployment::ResourceLimits;
+
+// Request bodies are camelCase like the query parameters, while ResourceLimits keeps the names it is stored with
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceLimitsRequest {
+  #[serde(default)]
+  pub cpus: Option<f64>,
+  #[serde(default)]
+  pub memory_mb: Option<i64>,
+  // Memory plus swap, -1 for unlimited swap
+  #[serde(default)]
+  pub memory_swap_mb: Option<i64>,
+  // -1 for unlimited
+  #[serde(default)]
+  pub pids: Option<i64>,
+}
+
+impl From<ResourceLimitsRequest> for ResourceLimits {
+  fn from(request: ResourceLimitsRequest) -> Self {
+    Self {
+      cpus: request.cpus,
+      memory_mb: request.memory_mb,
+      memory_swap_mb: request.memory_swap_mb,
+      pids: request.pids,
+    }
+  }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SetEnvironmentVariable {
+  pub value: String,
+  #[serde(default)]
+  pub secret: bool,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use super::logs::Logs;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateResourceLimits {
+  pub logs: Logs
+}
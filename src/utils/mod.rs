@@ -3,9 +3,13 @@ mod expect_error;
 mod gen_traefik_labels;
 pub mod http_client;
 pub mod runtime_helpers;
+mod secrets;
+mod verify_signature;
 
 pub use expect_error::*;
 // pub use get_free_port::*;
 pub use gen_traefik_labels::*;
+pub use secrets::*;
+pub use verify_signature::*;
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
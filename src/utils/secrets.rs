@@ -0,0 +1,92 @@
+use axum::http::StatusCode;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use lazy_static::lazy_static;
+use ring::{
+  aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+  rand::{SecureRandom, SystemRandom},
+};
+
+use crate::{configs::environment::SECRETS_KEY, types::other::voyager_error::VoyagerError};
+
+lazy_static! {
+  // AES-256-GCM key, from the base64 encoded 32 bytes in SECRETS_KEY
+  static ref SECRETS_CIPHER: Option<LessSafeKey> = SECRETS_KEY.as_ref().map(|key| {
+    let key = STANDARD
+      .decode(key.trim())
+      .unwrap_or_else(|e| panic!("Couldn't decode SECRETS_KEY as base64: {e}"));
+    let key = UnboundKey::new(&AES_256_GCM, &key)
+      .unwrap_or_else(|_| panic!("SECRETS_KEY must be 32 bytes long"));
+    LessSafeKey::new(key)
+  });
+}
+
+/// Decodes SECRETS_KEY, panicking if it is invalid, so that a bad key stops Voyager from starting instead of
+/// failing the first request that needs it.
+pub fn check_secrets_key() {
+  lazy_static::initialize(&SECRETS_CIPHER);
+}
+
+/// Encrypts a secret to be stored at rest, as the base64 encoded nonce followed by the ciphertext.
+pub fn encrypt_secret(secret: &str) -> Result<String, VoyagerError> {
+  let cipher = SECRETS_CIPHER.as_ref().ok_or_else(VoyagerError::secrets_disabled)?;
+
+  let mut nonce = [0u8; NONCE_LEN];
+  SystemRandom::new()
+    .fill(&mut nonce)
+    .map_err(|_| VoyagerError::encrypt_secret())?;
+
+  let mut in_out = secret.as_bytes().to_vec();
+  cipher
+    .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut in_out)
+    .map_err(|_| VoyagerError::encrypt_secret())?;
+
+  Ok(STANDARD.encode([nonce.as_slice(), &in_out].concat()))
+}
+
+/// Decrypts a secret encrypted by `encrypt_secret`.
+pub fn decrypt_secret(encrypted: &str) -> Result<String, VoyagerError> {
+  let cipher = SECRETS_CIPHER.as_ref().ok_or_else(VoyagerError::secrets_disabled)?;
+
+  let encrypted = STANDARD.decode(encrypted).map_err(|_| VoyagerError::decrypt_secret())?;
+  if encrypted.len() < NONCE_LEN {
+    return Err(VoyagerError::decrypt_secret());
+  }
+  let (nonce, ciphertext) = encrypted.split_at(NONCE_LEN);
+  let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| VoyagerError::decrypt_secret())?;
+
+  let mut in_out = ciphertext.to_vec();
+  let secret = cipher
+    .open_in_place(nonce, Aad::empty(), &mut in_out)
+    .map_err(|_| VoyagerError::decrypt_secret())?;
+
+  String::from_utf8(secret.to_vec()).map_err(|_| VoyagerError::decrypt_secret())
+}
+
+impl VoyagerError {
+  fn secrets_disabled() -> Self {
+    Self::new(
+      "Secrets are disabled, as SECRETS_KEY is not set".to_string(),
+      StatusCode::BAD_REQUEST,
+      false,
+      None,
+    )
+  }
+
+  fn encrypt_secret() -> Self {
+    Self::new(
+      "Failed to encrypt secret".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      None,
+    )
+  }
+
+  fn decrypt_secret() -> Self {
+    Self::new(
+      "Failed to decrypt secret. Was SECRETS_KEY changed?".to_string(),
+      StatusCode::INTERNAL_SERVER_ERROR,
+      false,
+      None,
+    )
+  }
+}
@@ -0,0 +1,15 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Checks a hex encoded HMAC-SHA256 `signature` of `payload`, comparing in constant time.
+pub fn verify_signature(secret: &str, payload: &[u8], signature: &str) -> bool {
+  let Ok(signature) = hex::decode(signature.trim()) else {
+    return false;
+  };
+
+  #[allow(clippy::unwrap_used)] // HMAC accepts keys of any size
+  let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+  mac.update(payload);
+
+  mac.verify_slice(&signature).is_ok()
+}